@@ -0,0 +1,491 @@
+// A color library grown from the `Color` struct in `another_display`, and the
+// `Color` enum in 08-flow-of-control's `match_enums`.
+//
+// `Color` is stored as 8-bit RGB, and converts to and from CMYK, HSL and HSV.
+// The other models use f64 components, so converting RGB to any of them and
+// back again gives the exact same color:
+//
+//     let color: Color = "#80ff5a".parse()?;
+//     let hsl = Hsl::from(color);
+//
+//     println!("{color}");      // RGB (128, 255, 90) 0x80FF5A
+//     println!("{hsl}");        // hsl(106.2, 100%, 67.6%)
+//     println!("{color:#x}");   // 0x80ff5a
+//     assert_eq!(Color::from(hsl), color);
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+pub mod ansi;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+// cyan, magenta, yellow and key (black), each from 0 to 1
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Cmyk {
+    pub cyan: f64,
+    pub magenta: f64,
+    pub yellow: f64,
+    pub key: f64,
+}
+
+// hue in degrees from 0 to 360, saturation and lightness from 0 to 1
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Hsl {
+    pub hue: f64,
+    pub saturation: f64,
+    pub lightness: f64,
+}
+
+// hue in degrees from 0 to 360, saturation and value from 0 to 1
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Hsv {
+    pub hue: f64,
+    pub saturation: f64,
+    pub value: f64,
+}
+
+// a color in any of the models above
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Model {
+    Rgb(Color),
+    Cmyk(Cmyk),
+    Hsl(Hsl),
+    Hsv(Hsv),
+}
+
+// the 16 basic CSS colors, plus orange
+const NAMED: [(&str, Color); 17] = [
+    ("black", Color::BLACK),
+    ("silver", Color::new(192, 192, 192)),
+    ("gray", Color::new(128, 128, 128)),
+    ("white", Color::WHITE),
+    ("maroon", Color::new(128, 0, 0)),
+    ("red", Color::RED),
+    ("purple", Color::new(128, 0, 128)),
+    ("fuchsia", Color::new(255, 0, 255)),
+    ("green", Color::GREEN),
+    ("lime", Color::new(0, 255, 0)),
+    ("olive", Color::new(128, 128, 0)),
+    ("yellow", Color::new(255, 255, 0)),
+    ("navy", Color::new(0, 0, 128)),
+    ("blue", Color::BLUE),
+    ("teal", Color::new(0, 128, 128)),
+    ("aqua", Color::new(0, 255, 255)),
+    ("orange", Color::new(255, 165, 0)),
+];
+
+impl Color {
+    pub const BLACK: Color = Color::new(0, 0, 0);
+    pub const WHITE: Color = Color::new(255, 255, 255);
+    pub const RED: Color = Color::new(255, 0, 0);
+    // as in CSS, green is only half as bright as red and blue - the full
+    // brightness green is "lime"
+    pub const GREEN: Color = Color::new(0, 128, 0);
+    pub const BLUE: Color = Color::new(0, 0, 255);
+
+    pub const fn new(red: u8, green: u8, blue: u8) -> Color {
+        Color { red, green, blue }
+    }
+
+    // the CSS name of this color, if it has one
+    pub fn name(&self) -> Option<&'static str> {
+        NAMED
+            .iter()
+            .find(|(_, color)| color == self)
+            .map(|(name, _)| *name)
+    }
+
+    // each channel as a fraction from 0 to 1
+    fn fractions(&self) -> (f64, f64, f64) {
+        (
+            f64::from(self.red) / 255.0,
+            f64::from(self.green) / 255.0,
+            f64::from(self.blue) / 255.0,
+        )
+    }
+
+    fn from_fractions(red: f64, green: f64, blue: f64) -> Color {
+        let channel = |value: f64| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+        Color::new(channel(red), channel(green), channel(blue))
+    }
+
+    // the hue, and the largest and smallest channels, shared by HSL and HSV
+    fn hue(&self) -> (f64, f64, f64) {
+        let (r, g, b) = self.fractions();
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+
+        (hue, max, min)
+    }
+
+    // the inverse of `hue`: a color with the given hue, chroma (the difference
+    // between the largest and smallest channels) and smallest channel
+    fn from_hue(hue: f64, chroma: f64, min: f64) -> Color {
+        let sector = hue.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+
+        let (r, g, b) = match sector as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        Color::from_fractions(r + min, g + min, b + min)
+    }
+}
+
+impl Cmyk {
+    pub fn new(cyan: f64, magenta: f64, yellow: f64, key: f64) -> Cmyk {
+        Cmyk {
+            cyan,
+            magenta,
+            yellow,
+            key,
+        }
+    }
+}
+
+impl Hsl {
+    pub fn new(hue: f64, saturation: f64, lightness: f64) -> Hsl {
+        Hsl {
+            hue,
+            saturation,
+            lightness,
+        }
+    }
+}
+
+impl Hsv {
+    pub fn new(hue: f64, saturation: f64, value: f64) -> Hsv {
+        Hsv {
+            hue,
+            saturation,
+            value,
+        }
+    }
+}
+
+impl From<Color> for Cmyk {
+    fn from(color: Color) -> Cmyk {
+        let (r, g, b) = color.fractions();
+        let max = r.max(g).max(b);
+
+        if max == 0.0 {
+            return Cmyk::new(0.0, 0.0, 0.0, 1.0);
+        }
+
+        Cmyk::new(1.0 - r / max, 1.0 - g / max, 1.0 - b / max, 1.0 - max)
+    }
+}
+
+impl From<Cmyk> for Color {
+    fn from(cmyk: Cmyk) -> Color {
+        let white = 1.0 - cmyk.key.clamp(0.0, 1.0);
+        let channel = |ink: f64| (1.0 - ink.clamp(0.0, 1.0)) * white;
+
+        Color::from_fractions(
+            channel(cmyk.cyan),
+            channel(cmyk.magenta),
+            channel(cmyk.yellow),
+        )
+    }
+}
+
+impl From<Color> for Hsl {
+    fn from(color: Color) -> Hsl {
+        let (hue, max, min) = color.hue();
+        let lightness = (max + min) / 2.0;
+        let saturation = if max == min {
+            0.0
+        } else {
+            (max - min) / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+
+        Hsl::new(hue, saturation, lightness)
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(hsl: Hsl) -> Color {
+        let lightness = hsl.lightness.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * hsl.saturation.clamp(0.0, 1.0);
+
+        Color::from_hue(hsl.hue, chroma, lightness - chroma / 2.0)
+    }
+}
+
+impl From<Color> for Hsv {
+    fn from(color: Color) -> Hsv {
+        let (hue, max, min) = color.hue();
+        let saturation = if max == 0.0 { 0.0 } else { (max - min) / max };
+
+        Hsv::new(hue, saturation, max)
+    }
+}
+
+impl From<Hsv> for Color {
+    fn from(hsv: Hsv) -> Color {
+        let value = hsv.value.clamp(0.0, 1.0);
+        let chroma = value * hsv.saturation.clamp(0.0, 1.0);
+
+        Color::from_hue(hsv.hue, chroma, value - chroma)
+    }
+}
+
+impl From<Model> for Color {
+    fn from(model: Model) -> Color {
+        match model {
+            Model::Rgb(color) => color,
+            Model::Cmyk(cmyk) => cmyk.into(),
+            Model::Hsl(hsl) => hsl.into(),
+            Model::Hsv(hsv) => hsv.into(),
+        }
+    }
+}
+
+impl From<Color> for Model {
+    fn from(color: Color) -> Model {
+        Model::Rgb(color)
+    }
+}
+
+impl From<Cmyk> for Model {
+    fn from(cmyk: Cmyk) -> Model {
+        Model::Cmyk(cmyk)
+    }
+}
+
+impl From<Hsl> for Model {
+    fn from(hsl: Hsl) -> Model {
+        Model::Hsl(hsl)
+    }
+}
+
+impl From<Hsv> for Model {
+    fn from(hsv: Hsv) -> Model {
+        Model::Hsv(hsv)
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((red, green, blue): (u8, u8, u8)) -> Color {
+        Color::new(red, green, blue)
+    }
+}
+
+// the format from the end of the formatted print chapter:
+// RGB (128, 255, 90) 0x80FF5A
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "RGB ({}, {}, {}) {:#X}",
+            self.red, self.green, self.blue, self
+        )
+    }
+}
+
+// pad each channel on the left with 0s to a width of 2, e.g. 80ff5a, or
+// 0x80ff5a with {:#x}
+impl fmt::LowerHex for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = if f.alternate() { "0x" } else { "" };
+        let hex = format!(
+            "{prefix}{:02x}{:02x}{:02x}",
+            self.red, self.green, self.blue
+        );
+
+        f.pad(&hex)
+    }
+}
+
+impl fmt::UpperHex for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = if f.alternate() { "0x" } else { "" };
+        let hex = format!(
+            "{prefix}{:02X}{:02X}{:02X}",
+            self.red, self.green, self.blue
+        );
+
+        f.pad(&hex)
+    }
+}
+
+// components are printed with the formatter's precision, or to 1 decimal
+// place with trailing zeros removed
+fn write_number(f: &mut fmt::Formatter<'_>, value: f64) -> fmt::Result {
+    match f.precision() {
+        Some(precision) => write!(f, "{value:.precision$}"),
+        // adding 0.0 turns -0 into 0
+        None => write!(f, "{}", (value * 10.0).round() / 10.0 + 0.0),
+    }
+}
+
+fn write_percent(f: &mut fmt::Formatter<'_>, value: f64) -> fmt::Result {
+    write_number(f, value * 100.0)?;
+    write!(f, "%")
+}
+
+// cmyk(0%, 50%, 64.7%, 0%)
+impl fmt::Display for Cmyk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cmyk(")?;
+        write_percent(f, self.cyan)?;
+        write!(f, ", ")?;
+        write_percent(f, self.magenta)?;
+        write!(f, ", ")?;
+        write_percent(f, self.yellow)?;
+        write!(f, ", ")?;
+        write_percent(f, self.key)?;
+        write!(f, ")")
+    }
+}
+
+// hsl(106.2, 100%, 67.6%)
+impl fmt::Display for Hsl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "hsl(")?;
+        write_number(f, self.hue)?;
+        write!(f, ", ")?;
+        write_percent(f, self.saturation)?;
+        write!(f, ", ")?;
+        write_percent(f, self.lightness)?;
+        write!(f, ")")
+    }
+}
+
+// hsv(106.2, 64.7%, 100%)
+impl fmt::Display for Hsv {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "hsv(")?;
+        write_number(f, self.hue)?;
+        write!(f, ", ")?;
+        write_percent(f, self.saturation)?;
+        write!(f, ", ")?;
+        write_percent(f, self.value)?;
+        write!(f, ")")
+    }
+}
+
+impl fmt::Display for Model {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Model::Rgb(color) => {
+                write!(f, "rgb({}, {}, {})", color.red, color.green, color.blue)
+            }
+            Model::Cmyk(cmyk) => fmt::Display::fmt(cmyk, f),
+            Model::Hsl(hsl) => fmt::Display::fmt(hsl, f),
+            Model::Hsv(hsv) => fmt::Display::fmt(hsv, f),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseColorError {
+    InvalidHex(String),
+    InvalidRgb(String),
+    UnknownName(String),
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseColorError::InvalidHex(s) => {
+                write!(f, "invalid hex color '{s}', expected #rgb or #rrggbb")
+            }
+            ParseColorError::InvalidRgb(s) => {
+                write!(f, "invalid color '{s}', expected rgb(0-255, 0-255, 0-255)")
+            }
+            ParseColorError::UnknownName(s) => write!(f, "unknown color name '{s}'"),
+        }
+    }
+}
+
+impl error::Error for ParseColorError {}
+
+// #rgb or #rrggbb
+fn parse_hex(s: &str) -> Result<Color, ParseColorError> {
+    let error = || ParseColorError::InvalidHex(s.to_string());
+    let digits = &s[1..];
+
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(error());
+    }
+
+    let channel = |i: usize, len: usize| u8::from_str_radix(&digits[i * len..(i + 1) * len], 16);
+
+    match digits.len() {
+        // each digit is repeated, so #f80 is #ff8800
+        3 => Ok(Color::new(
+            channel(0, 1).map_err(|_| error())? * 0x11,
+            channel(1, 1).map_err(|_| error())? * 0x11,
+            channel(2, 1).map_err(|_| error())? * 0x11,
+        )),
+        6 => Ok(Color::new(
+            channel(0, 2).map_err(|_| error())?,
+            channel(1, 2).map_err(|_| error())?,
+            channel(2, 2).map_err(|_| error())?,
+        )),
+        _ => Err(error()),
+    }
+}
+
+// rgb(r, g, b)
+fn parse_rgb(s: &str) -> Result<Color, ParseColorError> {
+    let error = || ParseColorError::InvalidRgb(s.to_string());
+    let channels: Vec<u8> = s
+        .strip_prefix("rgb(")
+        .and_then(|rest| rest.strip_suffix(')'))
+        .ok_or_else(error)?
+        .split(',')
+        .map(|channel| channel.trim().parse().map_err(|_| error()))
+        .collect::<Result<_, _>>()?;
+
+    match channels[..] {
+        [red, green, blue] => Ok(Color::new(red, green, blue)),
+        _ => Err(error()),
+    }
+}
+
+// "#80ff5a", "#8f5", "rgb(128, 255, 90)" or a name like "orange". Letters
+// may be in any case
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Color, Self::Err> {
+        let s = s.trim().to_ascii_lowercase();
+
+        if s.starts_with('#') {
+            parse_hex(&s)
+        } else if s.starts_with("rgb") {
+            parse_rgb(&s)
+        } else {
+            NAMED
+                .iter()
+                .find(|(name, _)| *name == s)
+                .map(|(_, color)| *color)
+                .ok_or(ParseColorError::UnknownName(s))
+        }
+    }
+}
@@ -0,0 +1,266 @@
+// Rendering text in a `Color` on a terminal, using ANSI escape sequences.
+//
+// Terminals support different numbers of colors, so the escape sequence is
+// picked from what the environment says the terminal supports:
+//
+//     COLORTERM=truecolor or 24bit   24-bit RGB
+//     TERM=*256color*                the nearest of 256 colors
+//     any other TERM                 the nearest of the 16 standard colors
+//     TERM=dumb, or no TERM          no color
+//
+// Setting NO_COLOR to anything turns color off regardless, see
+// https://no-color.org
+//
+//     let warning = Style::new().fg(Color::new(255, 165, 0)).bold();
+//
+//     println!("{}: disk almost full", warning.paint("warning"));
+//     println!("{}", Color::RED.paint("error"));
+use std::env;
+use std::fmt;
+use std::sync::OnceLock;
+
+use super::Color;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Support {
+    None,
+    Ansi16,
+    Ansi256,
+    TrueColor,
+}
+
+impl Support {
+    // what the current process's environment supports. The environment is
+    // only read the first time
+    pub fn detect() -> Support {
+        static SUPPORT: OnceLock<Support> = OnceLock::new();
+
+        *SUPPORT.get_or_init(|| Support::from_env(|name| env::var(name).ok()))
+    }
+
+    // what an environment supports, given a way to look up its variables
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Support {
+        if var("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+            return Support::None;
+        }
+
+        if let Some("truecolor" | "24bit") = var("COLORTERM").as_deref() {
+            return Support::TrueColor;
+        }
+
+        match var("TERM") {
+            None => Support::None,
+            Some(term) if term.is_empty() || term == "dumb" => Support::None,
+            Some(term) if term.contains("256color") => Support::Ansi256,
+            Some(_) => Support::Ansi16,
+        }
+    }
+}
+
+// the 16 standard colors, in the order of their escape codes: 30 to 37, then
+// the bright versions from 90 to 97. Terminals pick their own shades, so these
+// are only approximate
+const ANSI_16: [Color; 16] = [
+    Color::new(0, 0, 0),
+    Color::new(205, 0, 0),
+    Color::new(0, 205, 0),
+    Color::new(205, 205, 0),
+    Color::new(0, 0, 238),
+    Color::new(205, 0, 205),
+    Color::new(0, 205, 205),
+    Color::new(229, 229, 229),
+    Color::new(127, 127, 127),
+    Color::new(255, 0, 0),
+    Color::new(0, 255, 0),
+    Color::new(255, 255, 0),
+    Color::new(92, 92, 255),
+    Color::new(255, 0, 255),
+    Color::new(0, 255, 255),
+    Color::new(255, 255, 255),
+];
+
+// the levels of each channel in the 6x6x6 color cube of the 256 colors
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn distance(a: Color, b: Color) -> u32 {
+    let channel = |x: u8, y: u8| (i32::from(x) - i32::from(y)).pow(2) as u32;
+
+    channel(a.red, b.red) + channel(a.green, b.green) + channel(a.blue, b.blue)
+}
+
+impl Color {
+    // the index of the nearest of the 256 colors. 0 to 15 are the standard
+    // colors, which vary between terminals, so only the color cube (16 to 231)
+    // and the grays (232 to 255) are used
+    pub fn to_ansi256(self) -> u8 {
+        let nearest_level = |value: u8| {
+            (0..CUBE_LEVELS.len())
+                .min_by_key(|&i| (i32::from(CUBE_LEVELS[i]) - i32::from(value)).abs())
+                .unwrap()
+        };
+        let (r, g, b) = (
+            nearest_level(self.red),
+            nearest_level(self.green),
+            nearest_level(self.blue),
+        );
+        let cube = Color::new(CUBE_LEVELS[r], CUBE_LEVELS[g], CUBE_LEVELS[b]);
+
+        // grays go from 8 to 238 in steps of 10
+        let average = (u32::from(self.red) + u32::from(self.green) + u32::from(self.blue)) / 3;
+        let step = (average.saturating_sub(3) / 10).min(23) as u8;
+        let level = 8 + step * 10;
+        let gray = Color::new(level, level, level);
+
+        if distance(self, gray) < distance(self, cube) {
+            232 + step
+        } else {
+            16 + 36 * r as u8 + 6 * g as u8 + b as u8
+        }
+    }
+
+    // the index of the nearest of the 16 standard colors, where 8 to 15 are
+    // the bright versions of 0 to 7
+    pub fn to_ansi16(self) -> u8 {
+        (0..ANSI_16.len())
+            .min_by_key(|&i| distance(self, ANSI_16[i]))
+            .unwrap() as u8
+    }
+
+    // shorthand for painting `value` in this color
+    pub fn paint<T>(self, value: T) -> Painted<T> {
+        Style::new().fg(self).paint(value)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Style {
+    foreground: Option<Color>,
+    background: Option<Color>,
+    bold: bool,
+    dim: bool,
+    italic: bool,
+    underline: bool,
+}
+
+impl Style {
+    pub fn new() -> Style {
+        Style::default()
+    }
+
+    pub fn fg(self, color: Color) -> Style {
+        Style {
+            foreground: Some(color),
+            ..self
+        }
+    }
+
+    pub fn on(self, color: Color) -> Style {
+        Style {
+            background: Some(color),
+            ..self
+        }
+    }
+
+    pub fn bold(self) -> Style {
+        Style { bold: true, ..self }
+    }
+
+    pub fn dim(self) -> Style {
+        Style { dim: true, ..self }
+    }
+
+    pub fn italic(self) -> Style {
+        Style {
+            italic: true,
+            ..self
+        }
+    }
+
+    pub fn underline(self) -> Style {
+        Style {
+            underline: true,
+            ..self
+        }
+    }
+
+    pub fn is_plain(&self) -> bool {
+        *self == Style::default()
+    }
+
+    // `value` in this style, for whatever the terminal supports
+    pub fn paint<T>(self, value: T) -> Painted<T> {
+        self.paint_with(value, Support::detect())
+    }
+
+    pub fn paint_with<T>(self, value: T, support: Support) -> Painted<T> {
+        Painted {
+            value,
+            style: self,
+            support,
+        }
+    }
+
+    // the escape codes for this style, separated by `;`, e.g. "1;38;5;208"
+    fn codes(&self, support: Support) -> String {
+        let mut codes: Vec<String> = [
+            (self.bold, "1"),
+            (self.dim, "2"),
+            (self.italic, "3"),
+            (self.underline, "4"),
+        ]
+        .into_iter()
+        .filter(|(enabled, _)| *enabled)
+        .map(|(_, code)| code.to_string())
+        .collect();
+
+        // foreground codes start at 30, and background codes at 40
+        for (color, base) in [(self.foreground, 30), (self.background, 40)] {
+            let Some(color) = color else { continue };
+
+            codes.push(match support {
+                Support::None => continue,
+                Support::TrueColor => format!(
+                    "{};2;{};{};{}",
+                    base + 8,
+                    color.red,
+                    color.green,
+                    color.blue
+                ),
+                Support::Ansi256 => format!("{};5;{}", base + 8, color.to_ansi256()),
+                Support::Ansi16 => match color.to_ansi16() {
+                    bright @ 8.. => (base + 60 + bright - 8).to_string(),
+                    normal => (base + normal).to_string(),
+                },
+            });
+        }
+
+        codes.join(";")
+    }
+}
+
+// a value that is displayed in a style. Formatting options like width are
+// passed through to the value
+#[derive(Debug, Clone, Copy)]
+pub struct Painted<T> {
+    value: T,
+    style: Style,
+    support: Support,
+}
+
+impl<T> Painted<T> {
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Painted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.support == Support::None || self.style.is_plain() {
+            return fmt::Display::fmt(&self.value, f);
+        }
+
+        write!(f, "\x1b[{}m", self.style.codes(self.support))?;
+        fmt::Display::fmt(&self.value, f)?;
+        write!(f, "\x1b[0m")
+    }
+}
@@ -0,0 +1,272 @@
+// A latitude and longitude, grown from the `City` struct in `another_display`.
+//
+// A `Coordinate` is always in range: latitude from -90 to 90, and longitude
+// from -180 to 180, with negative values south and west. It's displayed with
+// 4 decimal places unless the formatter asks for a precision, and in degrees,
+// minutes and seconds with the alternate flag:
+//
+//     let dublin: Coordinate = "53.347778, -6.259722".parse()?;
+//
+//     println!("{dublin}");     // 53.3478°N 6.2597°W
+//     println!("{dublin:.2}");  // 53.35°N 6.26°W
+//     println!("{dublin:#}");   // 53°20'52"N 6°15'35"W
+//
+// Both forms can be parsed back with `FromStr`.
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+// the mean radius of the Earth
+pub const EARTH_RADIUS_KM: f64 = 6371.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Coordinate {
+    lat: f64,
+    lon: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CoordinateError {
+    InvalidLatitude(f64),
+    InvalidLongitude(f64),
+    InvalidFormat(String),
+}
+
+impl fmt::Display for CoordinateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoordinateError::InvalidLatitude(lat) => {
+                write!(f, "latitude {lat} is not between -90 and 90")
+            }
+            CoordinateError::InvalidLongitude(lon) => {
+                write!(f, "longitude {lon} is not between -180 and 180")
+            }
+            CoordinateError::InvalidFormat(s) => write!(f, "invalid coordinate '{s}'"),
+        }
+    }
+}
+
+impl error::Error for CoordinateError {}
+
+impl Coordinate {
+    pub fn new(lat: f64, lon: f64) -> Result<Coordinate, CoordinateError> {
+        if !(-90.0..=90.0).contains(&lat) {
+            return Err(CoordinateError::InvalidLatitude(lat));
+        }
+
+        if !(-180.0..=180.0).contains(&lon) {
+            return Err(CoordinateError::InvalidLongitude(lon));
+        }
+
+        Ok(Coordinate { lat, lon })
+    }
+
+    pub fn lat(&self) -> f64 {
+        self.lat
+    }
+
+    pub fn lon(&self) -> f64 {
+        self.lon
+    }
+
+    // the great-circle distance in kilometres, using the haversine formula
+    pub fn distance_to(&self, other: &Coordinate) -> f64 {
+        let (lat1, lat2) = (self.lat.to_radians(), other.lat.to_radians());
+        let d_lat = lat2 - lat1;
+        let d_lon = (other.lon - self.lon).to_radians();
+
+        let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+
+        2.0 * EARTH_RADIUS_KM * a.sqrt().atan2((1.0 - a).sqrt())
+    }
+
+    // the initial bearing from here to `other`, in degrees clockwise from
+    // north. Following a great circle, the bearing changes along the way
+    pub fn bearing_to(&self, other: &Coordinate) -> f64 {
+        let (lat1, lat2) = (self.lat.to_radians(), other.lat.to_radians());
+        let d_lon = (other.lon - self.lon).to_radians();
+
+        let y = d_lon.sin() * lat2.cos();
+        let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * d_lon.cos();
+
+        y.atan2(x).to_degrees().rem_euclid(360.0)
+    }
+}
+
+// the most decimal places of seconds that are worked out. 180° is 648,000
+// seconds, so with 9 places the total fits in the 53 bits an f64 holds
+// exactly, and in a u64. Further places are only noise, and are written as 0s
+const MAX_DMS_PRECISION: usize = 9;
+
+// 53°20'52"N, with `precision` decimal places for the seconds
+fn write_dms(
+    f: &mut fmt::Formatter<'_>,
+    value: f64,
+    hemisphere: char,
+    precision: usize,
+) -> fmt::Result {
+    // round once, in units of the last decimal place of the seconds, so
+    // 59.99 seconds rounds up to the next minute instead of to 60
+    let places = precision.min(MAX_DMS_PRECISION);
+    let scale = 10_u64.pow(places as u32);
+    let total = (value.abs() * 3600.0 * scale as f64).round() as u64;
+    let degrees = total / (3600 * scale);
+    let minutes = total / (60 * scale) % 60;
+    let seconds = total % (60 * scale);
+
+    write!(f, "{degrees}°{minutes}'{}", seconds / scale)?;
+
+    if precision > 0 {
+        write!(
+            f,
+            ".{:0places$}{:0<zeros$}",
+            seconds % scale,
+            "",
+            zeros = precision - places
+        )?;
+    }
+
+    write!(f, "\"{hemisphere}")
+}
+
+impl fmt::Display for Coordinate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lat_c = if self.lat >= 0.0 { 'N' } else { 'S' };
+        let lon_c = if self.lon >= 0.0 { 'E' } else { 'W' };
+
+        if f.alternate() {
+            let precision = f.precision().unwrap_or(0);
+
+            write_dms(f, self.lat, lat_c, precision)?;
+            write!(f, " ")?;
+            write_dms(f, self.lon, lon_c, precision)
+        } else {
+            let precision = f.precision().unwrap_or(4);
+
+            write!(
+                f,
+                "{lat:.precision$}°{lat_c} {lon:.precision$}°{lon_c}",
+                lat = self.lat.abs(),
+                lon = self.lon.abs()
+            )
+        }
+    }
+}
+
+// one half of a coordinate: a signed or unsigned number of degrees, optionally
+// followed by minutes and seconds, and a hemisphere
+struct Angle {
+    degrees: f64,
+    hemisphere: Option<char>,
+}
+
+// reads angles from the start of a string, e.g. `-6.2597`, `53.3478°N` or
+// `53°20'52.1"N`
+struct AngleParser<'a> {
+    rest: &'a str,
+}
+
+impl<'a> AngleParser<'a> {
+    fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    fn eat(&mut self, options: &[char]) -> Option<char> {
+        let c = self.rest.chars().next().filter(|c| options.contains(c))?;
+
+        self.rest = &self.rest[c.len_utf8()..];
+
+        Some(c)
+    }
+
+    fn number(&mut self) -> Option<f64> {
+        let end = self
+            .rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+            .unwrap_or(self.rest.len());
+        let value = self.rest[..end].parse().ok()?;
+
+        self.rest = &self.rest[end..];
+
+        Some(value)
+    }
+
+    // a number that can't be signed, and must be less than 60
+    fn sixtieths(&mut self) -> Option<f64> {
+        self.skip_whitespace();
+
+        if !self.rest.starts_with(|c: char| c.is_ascii_digit()) {
+            return None;
+        }
+
+        self.number().filter(|value| *value < 60.0)
+    }
+
+    fn angle(&mut self) -> Option<Angle> {
+        self.skip_whitespace();
+
+        let degrees = self.number()?;
+        let mut magnitude = degrees.abs();
+
+        if self.eat(&['°']).is_some() {
+            if let Some(minutes) = self.sixtieths() {
+                self.eat(&['\'', '′'])?;
+                magnitude += minutes / 60.0;
+
+                if let Some(seconds) = self.sixtieths() {
+                    self.eat(&['"', '″'])?;
+                    magnitude += seconds / 3600.0;
+                }
+            }
+        }
+
+        self.skip_whitespace();
+
+        let hemisphere = self.eat(&['N', 'S', 'E', 'W', 'n', 's', 'e', 'w']);
+        let hemisphere = hemisphere.map(|c| c.to_ascii_uppercase());
+
+        // `-53°N` is ambiguous
+        if hemisphere.is_some() && degrees.is_sign_negative() {
+            return None;
+        }
+
+        let degrees = match hemisphere {
+            Some('S' | 'W') => -magnitude,
+            _ => magnitude.copysign(degrees),
+        };
+
+        Some(Angle {
+            degrees,
+            hemisphere,
+        })
+    }
+}
+
+// "53.347778, -6.259722", "53.3478°N 6.2597°W" or "53°20'52"N 6°15'35"W". The
+// latitude comes first
+impl FromStr for Coordinate {
+    type Err = CoordinateError;
+
+    fn from_str(s: &str) -> Result<Coordinate, Self::Err> {
+        let invalid = || CoordinateError::InvalidFormat(s.to_string());
+        let mut parser = AngleParser { rest: s };
+
+        let lat = parser.angle().ok_or_else(invalid)?;
+
+        parser.skip_whitespace();
+        parser.eat(&[',']);
+
+        let lon = parser.angle().ok_or_else(invalid)?;
+
+        parser.skip_whitespace();
+
+        let hemispheres_match = matches!(lat.hemisphere, None | Some('N' | 'S'))
+            && matches!(lon.hemisphere, None | Some('E' | 'W'));
+
+        if !parser.rest.is_empty() || !hemispheres_match {
+            return Err(invalid());
+        }
+
+        Coordinate::new(lat.degrees, lon.degrees)
+    }
+}
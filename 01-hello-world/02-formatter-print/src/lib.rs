@@ -0,0 +1,444 @@
+use rbe_registry::Chapter;
+use std::fmt;
+use std::sync::Arc;
+
+pub mod color;
+pub mod coordinate;
+pub mod log;
+pub mod pretty;
+pub mod table;
+
+use color::ansi::{Style, Support};
+use color::{Cmyk, Color, Hsl, Hsv, Model};
+use coordinate::Coordinate;
+use log::{Filter, Level, Logger, MemorySink, StderrSink};
+use pretty::{Doc, ToDoc};
+use table::{Align, Table};
+
+// allow for dead code to compile
+#[allow(dead_code)]
+struct UnusedStruct {}
+
+fn format_example() {
+    let a = format!("A dynamic value: {}", "foo");
+    println!("{}", a);
+
+    let a = format!("keyword arg: {value}", value = "foo");
+    println!("{}", a);
+
+    let value = "foo";
+    let a = format!("named arg: {value}");
+    println!("{}", a);
+
+    let a = format!("positioned args: {1} {0}", "first", "second");
+    println!("{}", a);
+
+    println!();
+}
+
+fn eprint_example() {
+    let a = "foo";
+    eprintln!("{}", a);
+}
+
+fn logging() {
+    // show info and above, debug for the table module, and nothing from
+    // the pretty printer
+    let filter: Filter = "info,formatted_print::table=debug,formatted_print::pretty=off"
+        .parse()
+        .unwrap();
+
+    for (level, target) in [
+        (Level::Debug, "formatted_print"),
+        (Level::Info, "formatted_print"),
+        (Level::Debug, "formatted_print::table"),
+        (Level::Trace, "formatted_print::table"),
+        (Level::Error, "formatted_print::pretty"),
+    ] {
+        println!(
+            "{level:<5} from {target}: {}",
+            filter.enabled(level, target)
+        );
+    }
+    println!();
+
+    // keep messages in memory, without timestamps so the output is the same
+    // on every run
+    let memory = Arc::new(MemorySink::new());
+    let logger = Logger::new(filter, Arc::clone(&memory)).with_timestamps(false);
+    let disk = "/dev/sda1";
+
+    crate::error!(logger: logger, "{disk} is full");
+    crate::warn!(logger: logger, "{disk} is {}% full", 95);
+    crate::info!(logger: logger, "checked {} disks", 3);
+    crate::debug!(logger: logger, "not logged, below info");
+
+    for line in memory.lines() {
+        println!("{line}");
+    }
+
+    // like eprintln!, but with a level and the module it came from
+    let logger = Logger::new(Filter::new(Level::Trace), StderrSink).with_timestamps(false);
+
+    crate::trace!(logger: logger, "written to stderr");
+}
+
+fn indentation() {
+    let a = "foo";
+    let x = 42;
+    let width = 10;
+
+    println!("occupy {width} using spaces right: |{:>width$}|", a);
+    println!("occupy {width} using spaces left: |{:<width$}|", a);
+    println!("occupy {width} using spaces centered: |{:^width$}|", a);
+    println!("occupy {width} using - centered: |{:-^width$}|", a);
+    println!(
+        "pad with leading zeros until width of {width}: |{:0width$}|",
+        x
+    );
+    println!(
+        "pad with leading zeros until width of {width}: |{:0width$}|",
+        -x
+    );
+    println!("decimal precision of {width}: |{:.width$}|", 5.0);
+    println!();
+}
+
+fn format_characters() {
+    #[derive(Debug)]
+    struct Foo {
+        bar: String,
+    }
+
+    impl fmt::Display for Foo {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "Here we have a Foo: {{ bar: {} }}", &self.bar)
+        }
+    }
+
+    let a = Foo {
+        bar: "baz".to_string(),
+    };
+    let x = 42;
+
+    println!("Display trait         {{}}: {}", a);
+    println!("Debug trait           {{:?}}: {:?}", a);
+    println!("binary {0}            {{:b}}: {0:b}", x);
+    println!("octal {0}             {{:o}}: {0:o}", x);
+    println!("hexadecimal lower {0} {{:x}}: {0:x}", x);
+    println!("hexadecimal upper {0} {{:X}}: {0:X}", x);
+    println!();
+
+    println!("alternate Display trait        {{:#}}: {:#}", a);
+    println!("alternate Debug trait          {{:#?}}: {:#?}", a);
+    println!("alternate binary {0}            {{:#b}}: {0:#b}", x);
+    println!("alternate octal {0}             {{:#o}}: {0:#o}", x);
+    println!("alternate hexadecimal lower {0} {{:#x}}: {0:#x}", x);
+    println!("alternate hexadecimal upper {0} {{:#X}}: {0:#X}", x);
+}
+
+fn impl_display_implements_to_string() {
+    struct Foo {
+        bar: String,
+    }
+
+    impl fmt::Display for Foo {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "Here we have a Foo: {{ bar: {} }}", &self.bar)
+        }
+    }
+
+    let a = Foo {
+        bar: "baz".to_string(),
+    };
+    // we didn't define .to_stirng() on Foo - it's automatially implemented
+    // when we implement Display
+    let foo_string = a.to_string();
+    println!("Foo.to_string: {}", foo_string);
+    println!();
+}
+
+fn display_vs_debug() {
+    #[derive(Debug)]
+    struct MinMax(i8, i8);
+
+    impl fmt::Display for MinMax {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "({}, {})", &self.0, &self.1)
+        }
+    }
+
+    impl fmt::Binary for MinMax {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "({:b}, {:b})", &self.0, &self.1)
+        }
+    }
+
+    let min_max = MinMax(-5, 10);
+
+    println!("Debug min_max: {min_max:?}");
+    println!("Pretty-print min_max: {min_max:#?}");
+    println!("Display min_max: {min_max}");
+    println!("alternate Display min_max: {min_max:#}");
+    println!("Binary min_max: {min_max:b}");
+    println!();
+}
+
+fn display_for_list() {
+    struct List(Vec<i32>);
+
+    impl fmt::Display for List {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            // Get the vector out of the type
+            // List is a tuple struct, so we use the index of the tuple to
+            // extract the value
+            let vec = &self.0;
+
+            // write the open parens to the formatter, using ? to handle
+            // the Result
+            write!(f, "[")?;
+
+            for (count, v) in vec.iter().enumerate() {
+                //                  [1]     [2]
+                // 1 - get an iterator from vec. This is an explicit analogue to
+                //      Python's __iter__ methods on objects
+                // 2 - as in Python, we can use enumerate to generate a tuple of
+                //      the index and value for iteration
+
+                // if we are beyond the first value, write a comma to the
+                // formatter
+                if count > 0 {
+                    write!(f, ", ")?;
+                }
+
+                // write the value to the formatter
+                write!(f, "{}: {}", count, v)?;
+            }
+
+            // append a closing parens
+            // Note that at this point we are returning write! - a Result
+            // We don't want to handle the Result using ? - the type signature
+            // expects that we return a result
+            write!(f, "]")
+        }
+    }
+
+    // the pretty printer writes the brackets and commas for us, and breaks
+    // the list over lines when it doesn't fit in the width
+    impl ToDoc for List {
+        fn to_doc(&self) -> Doc {
+            Doc::list(
+                self.0
+                    .iter()
+                    .enumerate()
+                    .map(|(count, v)| Doc::text(format!("{count}: {v}"))),
+            )
+        }
+    }
+
+    let array = [1, 2, 3];
+    let vec = array.to_vec();
+    let list = List(vec);
+
+    println!("{}", list);
+    println!("{}", list.to_doc());
+    println!();
+
+    let long_list = List((1..=8).map(|x| x * 111).collect());
+
+    println!("{:80}", long_list.to_doc());
+    println!("{:40}", long_list.to_doc());
+    println!();
+
+    // nested docs are only broken as far as they need to be
+    let nested = vec![vec![1, 2, 3], (10..20).collect(), vec![]];
+
+    println!("{:30}", nested.to_doc());
+    println!();
+}
+
+struct City {
+    name: &'static str,
+    location: Coordinate,
+}
+
+// passing the formatter through to the coordinate means {:.2} and {:#} apply
+// to it
+impl fmt::Display for City {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.name)?;
+        fmt::Display::fmt(&self.location, f)
+    }
+}
+
+#[allow(clippy::excessive_precision)]
+fn cities() -> [City; 3] {
+    [
+        ("Dublin", 53.347778, -6.259722),
+        ("Oslo", 59.95, 10.75),
+        ("Vancouver", 49.25, -123.1),
+    ]
+    .map(|(name, lat, lon)| City {
+        name,
+        location: Coordinate::new(lat, lon).unwrap(),
+    })
+}
+
+fn another_display() {
+    for city in cities().iter() {
+        println!("{}", city);
+    }
+    println!();
+
+    for color in [
+        Color::new(128, 255, 90),
+        Color::new(0, 3, 254),
+        Color::new(0, 0, 0),
+    ] {
+        println!("{color:?}");
+        println!("{color}");
+        println!("{color:x}");
+    }
+    println!();
+
+    // the same color in each color model, and parsed from a string
+    let color: Color = "#80ff5a".parse().unwrap();
+
+    println!("{}", Model::Rgb(color));
+    println!("{}", Cmyk::from(color));
+    println!("{}", Hsl::from(color));
+    println!("{:.3}", Hsv::from(color));
+    println!("round trip: {}", Color::from(Hsl::from(color)) == color);
+
+    for input in ["#f80", "rgb(0, 128, 128)", "Orange", "#12", "mauve"] {
+        match input.parse::<Color>() {
+            Ok(color) => println!("{input:?} => {color}, {:?}", color.name()),
+            Err(e) => println!("{input:?} => error: {e}"),
+        }
+    }
+}
+
+fn coordinates() {
+    let cities = cities();
+
+    for city in &cities {
+        println!("{city:.2}");
+        println!("{city:#}");
+    }
+    println!();
+
+    for (from, to) in [(0, 1), (1, 2), (2, 0)] {
+        let (from, to) = (&cities[from], &cities[to]);
+
+        println!(
+            "{} to {}: {:.0} km, heading {:.1}°",
+            from.name,
+            to.name,
+            from.location.distance_to(&to.location),
+            from.location.bearing_to(&to.location)
+        );
+    }
+    println!();
+
+    for input in [
+        "53.347778, -6.259722",
+        "59.9500°N 10.7500°E",
+        "49°15'0\"N 123°6'0\"W",
+        "91, 0",
+        "10°E 20°N",
+    ] {
+        match input.parse::<Coordinate>() {
+            Ok(coordinate) => println!("{input:?} => {coordinate}"),
+            Err(e) => println!("{input:?} => error: {e}"),
+        }
+    }
+}
+
+fn tables() {
+    let dublin = cities()[0].location;
+    let tokyo = City {
+        name: "東京 (Tokyo)",
+        location: Coordinate::new(35.6764, 139.65).unwrap(),
+    };
+    let mut table = Table::new(["city", "lat", "lon", "from Dublin (km)"]);
+
+    for city in cities().iter().chain([&tokyo]) {
+        let location = city.location;
+
+        table.push_row([
+            city.name.to_string(),
+            format!("{:.4}", location.lat()),
+            format!("{:.4}", location.lon()),
+            format!("{:.0}", dublin.distance_to(&location)),
+        ]);
+    }
+
+    // the numeric columns are right-aligned, and "東京" takes up 4 columns
+    println!("{table}");
+    println!();
+
+    // the fill, alignment and width flags apply to every cell
+    println!("{table:.^10}");
+    println!();
+
+    let table = table.with_align(0, Align::Right);
+
+    println!("{}", table.markdown());
+    println!();
+    print!("{}", table.csv());
+}
+
+fn ansi_colors() {
+    let orange: Color = "orange".parse().unwrap();
+    let warning = Style::new().fg(orange).bold();
+
+    // the escape sequences for each level of terminal support
+    for support in [
+        Support::TrueColor,
+        Support::Ansi256,
+        Support::Ansi16,
+        Support::None,
+    ] {
+        let painted = warning.paint_with("warning", support).to_string();
+
+        println!("{support:?}: {painted:?}");
+    }
+    println!();
+
+    println!(
+        "orange is {} of 256, and {} of 16",
+        orange.to_ansi256(),
+        orange.to_ansi16()
+    );
+    println!();
+
+    // these are only colored if the terminal supports it, and NO_COLOR
+    // isn't set. Width and alignment apply to the text inside the colors
+    println!("[{:^11}]", warning.paint("warning"));
+    println!("[{:<11}]", Color::RED.paint("error"));
+    println!(
+        "[{:>11}]",
+        Style::new().fg(Color::WHITE).on(Color::BLUE).paint("info")
+    );
+}
+
+pub const CHAPTER: Chapter = rbe_registry::chapter! {
+    number: 1,
+    name: "formatted_print",
+    examples: {
+        _: [
+            format_example,
+            eprint_example,
+            logging,
+            indentation,
+            format_characters,
+            impl_display_implements_to_string,
+            display_vs_debug,
+            display_for_list,
+            another_display,
+            coordinates,
+            tables,
+            ansi_colors,
+        ],
+    },
+};
@@ -0,0 +1,456 @@
+// A small leveled logger, grown from `eprint_example`: diagnostics go to
+// stderr like `eprintln!`, but each message has a level, and which levels are
+// shown can be set per module.
+//
+//     use formatted_print::{info, warn};
+//
+//     info!("loaded {} cities", cities.len());
+//     warn!("{input:?} is not a valid index");
+//
+// Without any setup, the macros log to stderr, filtered by the RBE_LOG
+// environment variable. It holds a default level, and overrides for modules
+// and everything inside them:
+//
+//     RBE_LOG=info,primitives=debug,formatted_print::table=off
+//
+// The level can be one of off, error, warn, info, debug or trace, and
+// defaults to warn. A program can install its own logger with `init`, e.g. to
+// log to a file instead, and each macro also takes a logger to use directly:
+//
+//     let memory = Arc::new(MemorySink::new());
+//     let logger = Logger::new(Filter::new(Level::Debug), memory.clone());
+//
+//     debug!(logger: logger, "x is {x}");
+use std::error;
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// the environment variable read by `Filter::from_env`
+pub const ENV_VAR: &str = "RBE_LOG";
+
+// from most to least severe, so a filter of Info shows Error, Warn and Info
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+
+        // pass the formatter on, so {:5} lines levels up
+        f.pad(name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseFilterError(String);
+
+impl fmt::Display for ParseFilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid log filter '{}', expected e.g. info,module=debug",
+            self.0
+        )
+    }
+}
+
+impl error::Error for ParseFilterError {}
+
+// a level, or None for "off"
+fn parse_level(s: &str) -> Result<Option<Level>, ParseFilterError> {
+    match s.trim().to_ascii_lowercase().as_str() {
+        "off" => Ok(None),
+        "error" => Ok(Some(Level::Error)),
+        "warn" => Ok(Some(Level::Warn)),
+        "info" => Ok(Some(Level::Info)),
+        "debug" => Ok(Some(Level::Debug)),
+        "trace" => Ok(Some(Level::Trace)),
+        _ => Err(ParseFilterError(s.to_string())),
+    }
+}
+
+impl FromStr for Level {
+    type Err = ParseFilterError;
+
+    fn from_str(s: &str) -> Result<Level, Self::Err> {
+        parse_level(s)?.ok_or_else(|| ParseFilterError(s.to_string()))
+    }
+}
+
+// the most verbose level shown by default, and for particular modules
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    default: Option<Level>,
+    modules: Vec<(String, Option<Level>)>,
+}
+
+impl Filter {
+    pub fn new(level: Level) -> Filter {
+        Filter {
+            default: Some(level),
+            modules: vec![],
+        }
+    }
+
+    pub fn off() -> Filter {
+        Filter {
+            default: None,
+            modules: vec![],
+        }
+    }
+
+    // the filter in RBE_LOG, or warn if it isn't set or can't be parsed
+    pub fn from_env() -> Filter {
+        std::env::var(ENV_VAR)
+            .ok()
+            .and_then(|filter| filter.parse().ok())
+            .unwrap_or(Filter::new(Level::Warn))
+    }
+
+    // show up to `level` for `module` and the modules inside it
+    pub fn with_module(mut self, module: &str, level: Option<Level>) -> Filter {
+        self.modules.push((module.to_string(), level));
+        self
+    }
+
+    // the most specific module wins, so `a::b=off` hides a::b even with `a=trace`
+    pub fn enabled(&self, level: Level, target: &str) -> bool {
+        let in_module = |module: &str| {
+            target == module
+                || target
+                    .strip_prefix(module)
+                    .is_some_and(|rest| rest.starts_with("::"))
+        };
+        let max = self
+            .modules
+            .iter()
+            .filter(|(module, _)| in_module(module))
+            .max_by_key(|(module, _)| module.len())
+            .map_or(self.default, |(_, level)| *level);
+
+        max.is_some_and(|max| level <= max)
+    }
+}
+
+// "info", or "warn,primitives=debug,formatted_print::table=off"
+impl FromStr for Filter {
+    type Err = ParseFilterError;
+
+    fn from_str(s: &str) -> Result<Filter, Self::Err> {
+        let mut filter = Filter::new(Level::Warn);
+
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((module, level)) if !module.trim().is_empty() => {
+                    let level = parse_level(level).map_err(|_| ParseFilterError(s.to_string()))?;
+
+                    filter = filter.with_module(module.trim(), level);
+                }
+                Some(_) => return Err(ParseFilterError(s.to_string())),
+                None => {
+                    filter.default =
+                        parse_level(directive).map_err(|_| ParseFilterError(s.to_string()))?;
+                }
+            }
+        }
+
+        Ok(filter)
+    }
+}
+
+// a message on its way to a sink
+#[derive(Debug, Clone, Copy)]
+pub struct Record<'a> {
+    pub level: Level,
+    // the module the message was logged from
+    pub target: &'a str,
+    pub args: fmt::Arguments<'a>,
+    pub time: Option<SystemTime>,
+}
+
+// 2026-10-18T09:15:02.123Z WARN  primitives: invalid digit found in string
+impl fmt::Display for Record<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(time) = self.time {
+            write!(f, "{} ", format_time(time))?;
+        }
+
+        write!(f, "{:<5} {}: {}", self.level, self.target, self.args)
+    }
+}
+
+// a UTC timestamp in RFC 3339 format, to the millisecond
+pub fn format_time(time: SystemTime) -> String {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let secs = since_epoch.as_secs();
+    let (days, secs_of_day) = ((secs / 86_400) as i64, secs % 86_400);
+
+    // convert days since 1970-01-01 to a date, from Howard Hinnant's
+    // "chrono-Compatible Low-Level Date Algorithms"
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        secs_of_day / 3600,
+        secs_of_day / 60 % 60,
+        secs_of_day % 60,
+        since_epoch.subsec_millis()
+    )
+}
+
+// somewhere for log messages to go. Sinks are shared between threads, so they
+// need their own locking
+pub trait Sink: Send + Sync {
+    fn write(&self, record: &Record<'_>);
+
+    fn flush(&self) {}
+}
+
+impl<S: Sink + ?Sized> Sink for Arc<S> {
+    fn write(&self, record: &Record<'_>) {
+        (**self).write(record)
+    }
+
+    fn flush(&self) {
+        (**self).flush()
+    }
+}
+
+impl<S: Sink + ?Sized> Sink for Box<S> {
+    fn write(&self, record: &Record<'_>) {
+        (**self).write(record)
+    }
+
+    fn flush(&self) {
+        (**self).flush()
+    }
+}
+
+// failing to log shouldn't bring the program down, so write errors are
+// ignored, unlike with eprintln!
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StderrSink;
+
+impl Sink for StderrSink {
+    fn write(&self, record: &Record<'_>) {
+        let _ = writeln!(io::stderr().lock(), "{record}");
+    }
+}
+
+// appends to a file
+#[derive(Debug)]
+pub struct FileSink {
+    file: Mutex<File>,
+}
+
+impl FileSink {
+    pub fn create(path: impl AsRef<Path>) -> io::Result<FileSink> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        Ok(FileSink {
+            file: Mutex::new(file),
+        })
+    }
+}
+
+impl Sink for FileSink {
+    fn write(&self, record: &Record<'_>) {
+        if let Ok(mut file) = self.file.lock() {
+            let _ = writeln!(file, "{record}");
+        }
+    }
+
+    fn flush(&self) {
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.flush();
+        }
+    }
+}
+
+// keeps each formatted message, e.g. to check what a test logged
+#[derive(Debug, Default)]
+pub struct MemorySink {
+    lines: Mutex<Vec<String>>,
+}
+
+impl MemorySink {
+    pub fn new() -> MemorySink {
+        MemorySink::default()
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        self.lines.lock().unwrap().clone()
+    }
+
+    // remove and return everything logged so far
+    pub fn take(&self) -> Vec<String> {
+        std::mem::take(&mut *self.lines.lock().unwrap())
+    }
+}
+
+impl Sink for MemorySink {
+    fn write(&self, record: &Record<'_>) {
+        self.lines.lock().unwrap().push(record.to_string());
+    }
+}
+
+pub struct Logger {
+    filter: Filter,
+    sink: Box<dyn Sink>,
+    timestamps: bool,
+}
+
+impl fmt::Debug for Logger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Logger")
+            .field("filter", &self.filter)
+            .field("timestamps", &self.timestamps)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Logger {
+    pub fn new(filter: Filter, sink: impl Sink + 'static) -> Logger {
+        Logger {
+            filter,
+            sink: Box::new(sink),
+            timestamps: true,
+        }
+    }
+
+    // the default logger: stderr, filtered by RBE_LOG
+    pub fn from_env() -> Logger {
+        Logger::new(Filter::from_env(), StderrSink)
+    }
+
+    pub fn with_timestamps(self, timestamps: bool) -> Logger {
+        Logger { timestamps, ..self }
+    }
+
+    pub fn filter(&self) -> &Filter {
+        &self.filter
+    }
+
+    pub fn enabled(&self, level: Level, target: &str) -> bool {
+        self.filter.enabled(level, target)
+    }
+
+    // called by the macros, with `target` set to the calling module
+    pub fn log(&self, level: Level, target: &str, args: fmt::Arguments<'_>) {
+        if !self.enabled(level, target) {
+            return;
+        }
+
+        self.sink.write(&Record {
+            level,
+            target,
+            args,
+            time: self.timestamps.then(SystemTime::now),
+        });
+    }
+
+    pub fn flush(&self) {
+        self.sink.flush()
+    }
+}
+
+static LOGGER: OnceLock<Logger> = OnceLock::new();
+
+// install the logger used by the macros. It can only be set once, before
+// anything is logged - otherwise `logger` is handed back
+pub fn init(logger: Logger) -> Result<(), Logger> {
+    LOGGER.set(logger)
+}
+
+// the logger used by the macros, which is `Logger::from_env` unless `init` was
+// called first
+pub fn logger() -> &'static Logger {
+    LOGGER.get_or_init(Logger::from_env)
+}
+
+// log!(Level::Info, "x is {x}"), or log!(logger: my_logger, Level::Info, ...)
+#[macro_export]
+macro_rules! log {
+    (logger: $logger:expr, $level:expr, $($arg:tt)+) => {
+        $logger.log($level, module_path!(), format_args!($($arg)+))
+    };
+    ($level:expr, $($arg:tt)+) => {
+        $crate::log::logger().log($level, module_path!(), format_args!($($arg)+))
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    (logger: $logger:expr, $($arg:tt)+) => {
+        $crate::log!(logger: $logger, $crate::log::Level::Error, $($arg)+)
+    };
+    ($($arg:tt)+) => {
+        $crate::log!($crate::log::Level::Error, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! warn {
+    (logger: $logger:expr, $($arg:tt)+) => {
+        $crate::log!(logger: $logger, $crate::log::Level::Warn, $($arg)+)
+    };
+    ($($arg:tt)+) => {
+        $crate::log!($crate::log::Level::Warn, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    (logger: $logger:expr, $($arg:tt)+) => {
+        $crate::log!(logger: $logger, $crate::log::Level::Info, $($arg)+)
+    };
+    ($($arg:tt)+) => {
+        $crate::log!($crate::log::Level::Info, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    (logger: $logger:expr, $($arg:tt)+) => {
+        $crate::log!(logger: $logger, $crate::log::Level::Debug, $($arg)+)
+    };
+    ($($arg:tt)+) => {
+        $crate::log!($crate::log::Level::Debug, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    (logger: $logger:expr, $($arg:tt)+) => {
+        $crate::log!(logger: $logger, $crate::log::Level::Trace, $($arg)+)
+    };
+    ($($arg:tt)+) => {
+        $crate::log!($crate::log::Level::Trace, $($arg)+)
+    };
+}
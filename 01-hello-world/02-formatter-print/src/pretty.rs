@@ -0,0 +1,419 @@
+// A pretty printer in the style of Philip Wadler's "A prettier printer", for
+// laying out nested lists, structs and maps without hand-writing brackets,
+// commas and indentation as in `display_for_list`.
+//
+// A `Doc` describes every way a value could be laid out. `line` is a space,
+// and `softline` is nothing, unless the `group` they're in doesn't fit on the
+// current line - then every line in the group becomes a newline, indented by
+// the `nest`s around it:
+//
+//     let doc = Doc::list((1..=12).map(|n| Doc::text(n.to_string())));
+//
+//     println!("{}", doc.pretty(80));  // [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]
+//     println!("{}", doc.pretty(20));  // [
+//                                      //     1,
+//                                      //     2,
+//                                      //     ...
+//                                      // ]
+//
+// Values can describe their own layout by implementing `ToDoc`.
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::{self, Write};
+use std::ops::Add;
+
+// the number of spaces for each level of nesting, as in {:#?}
+pub const INDENT: usize = 4;
+
+// the width used by `Display`, unless the formatter sets one
+pub const DEFAULT_WIDTH: usize = 80;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node {
+    Nil,
+    Text(String),
+    // a newline, or the given text when its group fits on one line
+    Line(&'static str),
+    // a newline, even when its group would otherwise fit
+    HardLine,
+    // text that only appears when its group is broken over lines
+    IfBroken(String),
+    Concat(Vec<Node>),
+    Nest(usize, Box<Node>),
+    Group(Box<Node>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Doc(Node);
+
+impl Doc {
+    pub fn nil() -> Doc {
+        Doc(Node::Nil)
+    }
+
+    // text may not contain newlines - use `hardline` instead
+    pub fn text(text: impl Into<String>) -> Doc {
+        Doc(Node::Text(text.into()))
+    }
+
+    pub fn line() -> Doc {
+        Doc(Node::Line(" "))
+    }
+
+    pub fn softline() -> Doc {
+        Doc(Node::Line(""))
+    }
+
+    pub fn hardline() -> Doc {
+        Doc(Node::HardLine)
+    }
+
+    pub fn if_broken(text: impl Into<String>) -> Doc {
+        Doc(Node::IfBroken(text.into()))
+    }
+
+    pub fn concat(docs: impl IntoIterator<Item = Doc>) -> Doc {
+        Doc(Node::Concat(docs.into_iter().map(|doc| doc.0).collect()))
+    }
+
+    // `docs` with `separator` between each of them
+    pub fn join(docs: impl IntoIterator<Item = Doc>, separator: Doc) -> Doc {
+        let mut nodes = vec![];
+
+        for (i, doc) in docs.into_iter().enumerate() {
+            if i > 0 {
+                nodes.push(separator.0.clone());
+            }
+
+            nodes.push(doc.0);
+        }
+
+        Doc(Node::Concat(nodes))
+    }
+
+    pub fn append(self, other: Doc) -> Doc {
+        match self.0 {
+            Node::Concat(mut nodes) => {
+                nodes.push(other.0);
+
+                Doc(Node::Concat(nodes))
+            }
+            node => Doc(Node::Concat(vec![node, other.0])),
+        }
+    }
+
+    // indent the lines inside this doc by `indent` more spaces
+    pub fn nest(self, indent: usize) -> Doc {
+        Doc(Node::Nest(indent, Box::new(self.0)))
+    }
+
+    // lay this doc out on one line if it fits, otherwise break all of its
+    // lines
+    pub fn group(self) -> Doc {
+        Doc(Node::Group(Box::new(self.0)))
+    }
+
+    // `open`, then `items` separated by commas, then `close`. When broken,
+    // each item goes on its own indented line with a trailing comma, and
+    // `padding` is what goes inside the brackets when not
+    fn enclose(open: String, items: Vec<Doc>, close: &str, padding: fn() -> Doc) -> Doc {
+        if items.is_empty() {
+            return Doc::text(open + close);
+        }
+
+        let separator = Doc::text(",").append(Doc::line());
+        let inner = padding()
+            .append(Doc::join(items, separator))
+            .append(Doc::if_broken(","));
+
+        Doc::text(open)
+            .append(inner.nest(INDENT))
+            .append(padding())
+            .append(Doc::text(close))
+            .group()
+    }
+
+    // [a, b, c]
+    pub fn list(items: impl IntoIterator<Item = Doc>) -> Doc {
+        Doc::enclose("[".into(), items.into_iter().collect(), "]", Doc::softline)
+    }
+
+    // {a, b, c}
+    pub fn set(items: impl IntoIterator<Item = Doc>) -> Doc {
+        Doc::enclose("{".into(), items.into_iter().collect(), "}", Doc::softline)
+    }
+
+    // {a: 1, b: 2}
+    pub fn map(entries: impl IntoIterator<Item = (Doc, Doc)>) -> Doc {
+        let items = entries
+            .into_iter()
+            .map(|(key, value)| key.append(Doc::text(": ")).append(value))
+            .collect();
+
+        Doc::enclose("{".into(), items, "}", Doc::softline)
+    }
+
+    // Name(a, b)
+    pub fn tuple_struct(name: &str, fields: impl IntoIterator<Item = Doc>) -> Doc {
+        let fields: Vec<_> = fields.into_iter().collect();
+
+        if fields.is_empty() {
+            return Doc::text(name);
+        }
+
+        Doc::enclose(format!("{name}("), fields, ")", Doc::softline)
+    }
+
+    // Name { a: 1, b: 2 }
+    pub fn record<'a>(name: &str, fields: impl IntoIterator<Item = (&'a str, Doc)>) -> Doc {
+        let items: Vec<_> = fields
+            .into_iter()
+            .map(|(field, value)| Doc::text(format!("{field}: ")).append(value))
+            .collect();
+
+        if items.is_empty() {
+            return Doc::text(name);
+        }
+
+        Doc::enclose(format!("{name} {{"), items, "}", Doc::line)
+    }
+
+    // a value's Debug output, as a single piece of text
+    pub fn debug<T: fmt::Debug + ?Sized>(value: &T) -> Doc {
+        Doc::text(format!("{value:?}"))
+    }
+
+    // a Display adapter that lays this doc out to fit in `width` columns
+    pub fn pretty(&self, width: usize) -> Pretty<'_> {
+        Pretty { doc: self, width }
+    }
+}
+
+impl Default for Doc {
+    fn default() -> Doc {
+        Doc::nil()
+    }
+}
+
+impl Add for Doc {
+    type Output = Doc;
+
+    fn add(self, other: Doc) -> Doc {
+        self.append(other)
+    }
+}
+
+impl From<&str> for Doc {
+    fn from(text: &str) -> Doc {
+        Doc::text(text)
+    }
+}
+
+impl From<String> for Doc {
+    fn from(text: String) -> Doc {
+        Doc::text(text)
+    }
+}
+
+pub struct Pretty<'a> {
+    doc: &'a Doc,
+    width: usize,
+}
+
+impl fmt::Display for Pretty<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render(&self.doc.0, self.width, f)
+    }
+}
+
+// {} lays the doc out in 80 columns, and {:40} in 40
+impl fmt::Display for Doc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render(&self.0, f.width().unwrap_or(DEFAULT_WIDTH), f)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Flat,
+    Break,
+}
+
+// what's left to print: each node with its indent, and whether the group it's
+// in is broken
+type Stack<'a> = Vec<(usize, Mode, &'a Node)>;
+
+fn render(node: &Node, width: usize, out: &mut impl Write) -> fmt::Result {
+    let mut column = 0;
+    let mut stack: Stack = vec![(0, Mode::Break, node)];
+
+    while let Some((indent, mode, node)) = stack.pop() {
+        match node {
+            Node::Nil => {}
+            Node::Text(text) => {
+                out.write_str(text)?;
+                column += text.chars().count();
+            }
+            Node::Line(flat) if mode == Mode::Flat => {
+                out.write_str(flat)?;
+                column += flat.chars().count();
+            }
+            Node::Line(_) | Node::HardLine => {
+                write!(out, "\n{:indent$}", "")?;
+                column = indent;
+            }
+            Node::IfBroken(text) => {
+                if mode == Mode::Break {
+                    out.write_str(text)?;
+                    column += text.chars().count();
+                }
+            }
+            Node::Concat(nodes) => {
+                stack.extend(nodes.iter().rev().map(|node| (indent, mode, node)));
+            }
+            Node::Nest(extra, node) => stack.push((indent + extra, mode, node)),
+            Node::Group(node) => {
+                let flat =
+                    mode == Mode::Flat || fits(width as isize - column as isize, node, &stack);
+                let mode = if flat { Mode::Flat } else { Mode::Break };
+
+                stack.push((indent, mode, node));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// whether `group` fits in `remaining` columns when laid out flat, along with
+// whatever follows it up to the next newline
+fn fits(mut remaining: isize, group: &Node, rest: &Stack) -> bool {
+    let mut pending = vec![(Mode::Flat, group)];
+    let mut rest = rest.iter().rev().map(|&(_, mode, node)| (mode, node));
+
+    while remaining >= 0 {
+        let Some((mode, node)) = pending.pop().or_else(|| rest.next()) else {
+            return true;
+        };
+
+        match node {
+            Node::Nil => {}
+            Node::Text(text) => remaining -= text.chars().count() as isize,
+            Node::Line(flat) => match mode {
+                Mode::Flat => remaining -= flat.chars().count() as isize,
+                Mode::Break => return true,
+            },
+            // a hard line inside the group means it can't be flat, but one
+            // after it ends the line
+            Node::HardLine => return mode == Mode::Break,
+            Node::IfBroken(text) => {
+                if mode == Mode::Break {
+                    remaining -= text.chars().count() as isize;
+                }
+            }
+            Node::Concat(nodes) => pending.extend(nodes.iter().rev().map(|node| (mode, node))),
+            Node::Nest(_, node) | Node::Group(node) => pending.push((mode, node)),
+        }
+    }
+
+    false
+}
+
+// a value that can describe how to lay itself out
+pub trait ToDoc {
+    fn to_doc(&self) -> Doc;
+}
+
+macro_rules! impl_to_doc_with_debug {
+    ($($t:ty),*) => {
+        $(
+            impl ToDoc for $t {
+                fn to_doc(&self) -> Doc {
+                    Doc::debug(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_to_doc_with_debug!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char, str,
+    String
+);
+
+impl<T: ToDoc + ?Sized> ToDoc for &T {
+    fn to_doc(&self) -> Doc {
+        (**self).to_doc()
+    }
+}
+
+impl<T: ToDoc + ?Sized> ToDoc for Box<T> {
+    fn to_doc(&self) -> Doc {
+        (**self).to_doc()
+    }
+}
+
+impl<T: ToDoc> ToDoc for Option<T> {
+    fn to_doc(&self) -> Doc {
+        match self {
+            Some(value) => Doc::tuple_struct("Some", [value.to_doc()]),
+            None => Doc::text("None"),
+        }
+    }
+}
+
+impl<T: ToDoc, E: ToDoc> ToDoc for Result<T, E> {
+    fn to_doc(&self) -> Doc {
+        match self {
+            Ok(value) => Doc::tuple_struct("Ok", [value.to_doc()]),
+            Err(e) => Doc::tuple_struct("Err", [e.to_doc()]),
+        }
+    }
+}
+
+impl<A: ToDoc, B: ToDoc> ToDoc for (A, B) {
+    fn to_doc(&self) -> Doc {
+        Doc::tuple_struct("", [self.0.to_doc(), self.1.to_doc()])
+    }
+}
+
+impl<T: ToDoc> ToDoc for [T] {
+    fn to_doc(&self) -> Doc {
+        Doc::list(self.iter().map(ToDoc::to_doc))
+    }
+}
+
+impl<T: ToDoc, const N: usize> ToDoc for [T; N] {
+    fn to_doc(&self) -> Doc {
+        self[..].to_doc()
+    }
+}
+
+impl<T: ToDoc> ToDoc for Vec<T> {
+    fn to_doc(&self) -> Doc {
+        self[..].to_doc()
+    }
+}
+
+impl<T: ToDoc, S> ToDoc for HashSet<T, S> {
+    fn to_doc(&self) -> Doc {
+        Doc::set(self.iter().map(ToDoc::to_doc))
+    }
+}
+
+impl<T: ToDoc> ToDoc for BTreeSet<T> {
+    fn to_doc(&self) -> Doc {
+        Doc::set(self.iter().map(ToDoc::to_doc))
+    }
+}
+
+impl<K: ToDoc, V: ToDoc, S> ToDoc for HashMap<K, V, S> {
+    fn to_doc(&self) -> Doc {
+        Doc::map(self.iter().map(|(k, v)| (k.to_doc(), v.to_doc())))
+    }
+}
+
+impl<K: ToDoc, V: ToDoc> ToDoc for BTreeMap<K, V> {
+    fn to_doc(&self) -> Doc {
+        Doc::map(self.iter().map(|(k, v)| (k.to_doc(), v.to_doc())))
+    }
+}
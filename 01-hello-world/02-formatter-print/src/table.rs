@@ -0,0 +1,376 @@
+// A table of rows of `Display` values, with columns sized to fit their widest
+// cell, for the kind of output `{:>10}` and friends are used to line up by
+// hand.
+//
+//     let mut table = Table::new(["city", "lat", "lon"]);
+//
+//     table.push_row(["Dublin", "53.3478", "-6.2597"]);
+//     table.push_row(["Oslo", "59.95", "10.75"]);
+//
+//     println!("{table}");
+//     // city        lat      lon
+//     // ------  -------  -------
+//     // Dublin  53.3478  -6.2597
+//     // Oslo      59.95    10.75
+//
+// Columns where every cell is a number are right-aligned, headers included,
+// unless told otherwise. Widths are measured in terminal columns rather than
+// bytes or chars, so "東京" is as wide as "Oslo", and a cell with line breaks
+// in it is spread over as many lines as it needs.
+//
+// `Display` honours the formatter's flags: the fill character pads cells, the
+// alignment applies to columns that haven't been given one, and the width is
+// the minimum width of every column, e.g. `{table:.<8}`. The same table can
+// also be rendered as Markdown with `table.markdown()`, or as CSV with
+// `table.csv()`.
+use std::fmt::{self, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Align {
+    Left,
+    Right,
+    Center,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+    align: Vec<Option<Align>>,
+}
+
+// the number of terminal columns `c` takes up: 0 for combining marks and other
+// zero-width characters, 2 for wide characters such as CJK and emoji, and 1
+// for everything else
+pub fn char_width(c: char) -> usize {
+    match c as u32 {
+        0x0300..=0x036F
+        | 0x0483..=0x0489
+        | 0x0591..=0x05BD
+        | 0x0610..=0x061A
+        | 0x064B..=0x065F
+        | 0x1AB0..=0x1AFF
+        | 0x1DC0..=0x1DFF
+        | 0x200B..=0x200F
+        | 0x20D0..=0x20FF
+        | 0xFE00..=0xFE0F
+        | 0xFE20..=0xFE2F => 0,
+        0x1100..=0x115F
+        | 0x2E80..=0x303E
+        | 0x3041..=0x33FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xA000..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1F64F
+        | 0x1F900..=0x1F9FF
+        | 0x20000..=0x2FFFD
+        | 0x30000..=0x3FFFD => 2,
+        _ => 1,
+    }
+}
+
+pub fn display_width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+// the lines of a cell, split at "\r\n", "\n" or "\r". An empty cell is one
+// empty line
+fn cell_lines(cell: &str) -> impl Iterator<Item = &str> {
+    cell.split("\r\n").flat_map(|line| line.split(['\n', '\r']))
+}
+
+// write `text` padded with `fill` to `width` columns
+fn pad(out: &mut impl Write, text: &str, width: usize, align: Align, fill: char) -> fmt::Result {
+    let padding = width.saturating_sub(display_width(text));
+    let (before, after) = match align {
+        Align::Left => (0, padding),
+        Align::Right => (padding, 0),
+        Align::Center => (padding / 2, padding - padding / 2),
+    };
+
+    for _ in 0..before {
+        out.write_char(fill)?;
+    }
+
+    out.write_str(text)?;
+
+    for _ in 0..after {
+        out.write_char(fill)?;
+    }
+
+    Ok(())
+}
+
+impl Table {
+    pub fn new(headers: impl IntoIterator<Item = impl fmt::Display>) -> Table {
+        let headers: Vec<_> = headers.into_iter().map(|h| h.to_string()).collect();
+
+        Table {
+            align: vec![None; headers.len()],
+            headers,
+            rows: vec![],
+        }
+    }
+
+    pub fn with_align(mut self, column: usize, align: Align) -> Table {
+        self.set_align(column, align);
+        self
+    }
+
+    pub fn set_align(&mut self, column: usize, align: Align) {
+        if column >= self.align.len() {
+            self.align.resize(column + 1, None);
+        }
+
+        self.align[column] = Some(align);
+    }
+
+    // rows with more cells than there are headers add columns with empty
+    // headers, and shorter rows are padded with empty cells
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = impl fmt::Display>) {
+        self.rows
+            .push(row.into_iter().map(|cell| cell.to_string()).collect());
+    }
+
+    pub fn headers(&self) -> &[String] {
+        &self.headers
+    }
+
+    pub fn rows(&self) -> &[Vec<String>] {
+        &self.rows
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    pub fn columns(&self) -> usize {
+        self.rows
+            .iter()
+            .map(Vec::len)
+            .chain([self.headers.len()])
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn cell(row: &[String], column: usize) -> &str {
+        row.get(column).map_or("", String::as_str)
+    }
+
+    // a column is numeric if it has at least one cell, and every cell that
+    // isn't empty is a finite number, so "NaN" and "inf" are text
+    pub fn is_numeric(&self, column: usize) -> bool {
+        let mut cells = self
+            .rows
+            .iter()
+            .map(|row| Table::cell(row, column).trim())
+            .filter(|cell| !cell.is_empty())
+            .peekable();
+
+        cells.peek().is_some() && cells.all(|cell| cell.parse::<f64>().is_ok_and(|n| n.is_finite()))
+    }
+
+    // the alignment of `column`, falling back to `default` for text
+    pub fn align(&self, column: usize, default: Align) -> Align {
+        match self.align.get(column).copied().flatten() {
+            Some(align) => align,
+            None if self.is_numeric(column) => Align::Right,
+            None => default,
+        }
+    }
+
+    // the width of each column in terminal columns, at least `min`
+    fn widths(&self, min: usize) -> Vec<usize> {
+        (0..self.columns())
+            .map(|column| {
+                self.rows
+                    .iter()
+                    .chain([&self.headers])
+                    .flat_map(|row| cell_lines(Table::cell(row, column)))
+                    .map(display_width)
+                    .chain([min])
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+
+    pub fn markdown(&self) -> Markdown<'_> {
+        Markdown(self)
+    }
+
+    pub fn csv(&self) -> Csv<'_> {
+        Csv(self)
+    }
+
+    fn write_text(
+        &self,
+        out: &mut impl Write,
+        fill: char,
+        default: Align,
+        min_width: usize,
+    ) -> fmt::Result {
+        let widths = self.widths(min_width);
+        let aligns: Vec<_> = (0..widths.len())
+            .map(|column| self.align(column, default))
+            .collect();
+        let separator: Vec<_> = widths.iter().map(|width| "-".repeat(*width)).collect();
+
+        let rows = [&self.headers, &separator].into_iter().chain(&self.rows);
+
+        for (i, row) in rows.enumerate() {
+            if i > 0 {
+                out.write_char('\n')?;
+            }
+
+            let cells: Vec<Vec<&str>> = (0..widths.len())
+                .map(|column| cell_lines(Table::cell(row, column)).collect())
+                .collect();
+            let height = cells.iter().map(Vec::len).max().unwrap_or(1);
+
+            for line_index in 0..height {
+                if line_index > 0 {
+                    out.write_char('\n')?;
+                }
+
+                let mut line = String::new();
+
+                for (column, width) in widths.iter().enumerate() {
+                    if column > 0 {
+                        line.push_str("  ");
+                    }
+
+                    let text = cells[column].get(line_index).copied().unwrap_or("");
+
+                    pad(&mut line, text, *width, aligns[column], fill)?;
+                }
+
+                // don't leave spaces at the end of lines
+                out.write_str(line.trim_end_matches(' '))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let default = match f.align() {
+            Some(fmt::Alignment::Right) => Align::Right,
+            Some(fmt::Alignment::Center) => Align::Center,
+            Some(fmt::Alignment::Left) | None => Align::Left,
+        };
+
+        self.write_text(f, f.fill(), default, f.width().unwrap_or(0))
+    }
+}
+
+// a GitHub-flavoured Markdown table, with cells padded so that the source
+// lines up too
+pub struct Markdown<'a>(&'a Table);
+
+impl fmt::Display for Markdown<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let table = self.0;
+        // a row has to stay on one line, so line breaks become <br>
+        let escape = |cell: &str| {
+            cell.replace('|', "\\|")
+                .replace("\r\n", "<br>")
+                .replace(['\n', '\r'], "<br>")
+        };
+        let escaped = |row: &[String]| -> Vec<String> {
+            (0..table.columns())
+                .map(|column| escape(Table::cell(row, column)))
+                .collect()
+        };
+
+        let headers = escaped(&table.headers);
+        let rows: Vec<_> = table.rows.iter().map(|row| escaped(row)).collect();
+        // the delimiter row needs at least 3 characters
+        let widths: Vec<_> = (0..headers.len())
+            .map(|column| {
+                rows.iter()
+                    .chain([&headers])
+                    .map(|row| display_width(&row[column]))
+                    .chain([3])
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let aligns: Vec<_> = (0..widths.len())
+            .map(|column| table.align(column, Align::Left))
+            .collect();
+
+        let write_row = |f: &mut fmt::Formatter<'_>, row: &[String]| -> fmt::Result {
+            write!(f, "|")?;
+
+            for (column, cell) in row.iter().enumerate() {
+                write!(f, " ")?;
+                pad(f, cell, widths[column], aligns[column], ' ')?;
+                write!(f, " |")?;
+            }
+
+            Ok(())
+        };
+
+        write_row(f, &headers)?;
+        write!(f, "\n|")?;
+
+        for (width, align) in widths.iter().zip(&aligns) {
+            let dashes = "-".repeat(width - 1);
+
+            match align {
+                Align::Left => write!(f, " :{dashes} |")?,
+                Align::Right => write!(f, " {dashes}: |")?,
+                Align::Center => write!(f, " :{}: |", &dashes[1..])?,
+            }
+        }
+
+        for row in &rows {
+            writeln!(f)?;
+            write_row(f, row)?;
+        }
+
+        Ok(())
+    }
+}
+
+// RFC 4180 CSV, with the headers as the first record. Fields are only quoted
+// when they contain a comma, quote or line break
+pub struct Csv<'a>(&'a Table);
+
+impl fmt::Display for Csv<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let table = self.0;
+
+        for row in [&table.headers].into_iter().chain(&table.rows) {
+            for column in 0..table.columns() {
+                if column > 0 {
+                    write!(f, ",")?;
+                }
+
+                let cell = Table::cell(row, column);
+
+                if cell.contains([',', '"', '\r', '\n']) {
+                    write!(f, "\"{}\"", cell.replace('"', "\"\""))?;
+                } else {
+                    write!(f, "{cell}")?;
+                }
+            }
+
+            write!(f, "\r\n")?;
+        }
+
+        Ok(())
+    }
+}
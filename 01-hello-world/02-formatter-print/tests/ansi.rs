@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod ansi_tests {
+    use formatted_print::color::ansi::{Style, Support};
+    use formatted_print::color::Color;
+
+    fn support(vars: &[(&str, &str)]) -> Support {
+        Support::from_env(|name| {
+            vars.iter()
+                .find(|(var, _)| *var == name)
+                .map(|(_, value)| value.to_string())
+        })
+    }
+
+    #[test]
+    fn detects_support_from_the_environment() {
+        assert_eq!(support(&[]), Support::None);
+        assert_eq!(support(&[("TERM", "dumb")]), Support::None);
+        assert_eq!(support(&[("TERM", "xterm")]), Support::Ansi16);
+        assert_eq!(support(&[("TERM", "xterm-256color")]), Support::Ansi256);
+        assert_eq!(
+            support(&[("TERM", "xterm"), ("COLORTERM", "truecolor")]),
+            Support::TrueColor
+        );
+        assert_eq!(support(&[("COLORTERM", "24bit")]), Support::TrueColor);
+        assert_eq!(
+            support(&[("TERM", "xterm-256color"), ("COLORTERM", "yes")]),
+            Support::Ansi256
+        );
+    }
+
+    #[test]
+    fn no_color_turns_color_off() {
+        let vars = [("COLORTERM", "truecolor"), ("TERM", "xterm-256color")];
+
+        assert_eq!(
+            support(&[vars[0], vars[1], ("NO_COLOR", "1")]),
+            Support::None
+        );
+        // an empty NO_COLOR is ignored
+        assert_eq!(
+            support(&[vars[0], vars[1], ("NO_COLOR", "")]),
+            Support::TrueColor
+        );
+    }
+
+    #[test]
+    fn escape_sequences() {
+        let style = Style::new()
+            .fg(Color::new(255, 165, 0))
+            .on(Color::BLACK)
+            .bold();
+        let paint = |support| style.paint_with("hi", support).to_string();
+
+        assert_eq!(
+            paint(Support::TrueColor),
+            "\x1b[1;38;2;255;165;0;48;2;0;0;0mhi\x1b[0m"
+        );
+        assert_eq!(paint(Support::Ansi256), "\x1b[1;38;5;214;48;5;16mhi\x1b[0m");
+        assert_eq!(paint(Support::Ansi16), "\x1b[1;33;40mhi\x1b[0m");
+        assert_eq!(paint(Support::None), "hi");
+        assert_eq!(
+            Style::new()
+                .paint_with("plain", Support::TrueColor)
+                .to_string(),
+            "plain"
+        );
+        assert_eq!(
+            Style::new()
+                .fg(Color::new(255, 0, 0))
+                .underline()
+                .paint_with("bright", Support::Ansi16)
+                .to_string(),
+            "\x1b[4;91mbright\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn nearest_palette_colors() {
+        assert_eq!(Color::BLACK.to_ansi256(), 16);
+        assert_eq!(Color::WHITE.to_ansi256(), 231);
+        assert_eq!(Color::new(255, 0, 0).to_ansi256(), 196);
+        assert_eq!(Color::new(128, 128, 128).to_ansi256(), 244);
+        assert_eq!(Color::new(18, 18, 18).to_ansi256(), 233);
+        assert_eq!(Color::new(0, 0, 128).to_ansi16(), 4);
+        assert_eq!(Color::new(250, 250, 250).to_ansi16(), 15);
+        assert_eq!(Color::new(128, 128, 128).to_ansi16(), 8);
+    }
+
+    #[test]
+    fn formatting_options_apply_to_the_value() {
+        let painted = Style::new()
+            .fg(Color::RED)
+            .paint_with("ok", Support::TrueColor);
+
+        assert_eq!(format!("[{painted:>4}]"), "[\x1b[38;2;255;0;0m  ok\x1b[0m]");
+    }
+}
@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod color_tests {
+    use formatted_print::color::{Cmyk, Color, Hsl, Hsv, Model, ParseColorError};
+
+    // every 5th value of each channel, plus the values next to the extremes
+    fn sample() -> impl Iterator<Item = Color> {
+        let channel = || (0..=255).step_by(5).chain([1, 254]);
+
+        channel().flat_map(move |r| {
+            channel().flat_map(move |g| channel().map(move |b| Color::new(r, g, b)))
+        })
+    }
+
+    #[test]
+    fn round_trips_through_every_model() {
+        for color in sample() {
+            assert_eq!(Color::from(Cmyk::from(color)), color, "cmyk");
+            assert_eq!(Color::from(Hsl::from(color)), color, "hsl");
+            assert_eq!(Color::from(Hsv::from(color)), color, "hsv");
+
+            for model in [
+                Model::from(color),
+                Model::from(Cmyk::from(color)),
+                Model::from(Hsl::from(color)),
+                Model::from(Hsv::from(color)),
+            ] {
+                assert_eq!(Color::from(model), color, "{model:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn known_conversions() {
+        let orange = Color::new(255, 165, 0);
+
+        assert_eq!(Hsv::from(orange).to_string(), "hsv(38.8, 100%, 100%)");
+        assert_eq!(Hsl::from(orange).to_string(), "hsl(38.8, 100%, 50%)");
+        assert_eq!(Cmyk::from(orange).to_string(), "cmyk(0%, 35.3%, 100%, 0%)");
+        assert_eq!(Cmyk::from(Color::BLACK), Cmyk::new(0.0, 0.0, 0.0, 1.0));
+        assert_eq!(Color::from(Hsl::new(240.0, 1.0, 0.5)), Color::BLUE);
+        assert_eq!(
+            Color::from(Hsv::new(-240.0, 1.0, 1.0)),
+            Color::new(0, 255, 0)
+        );
+        assert_eq!(
+            Color::from(Hsl::new(0.0, 0.0, 0.5)),
+            Color::new(128, 128, 128)
+        );
+        assert_eq!(
+            format!("{:.2}", Hsl::from(Color::RED)),
+            "hsl(0.00, 100.00%, 50.00%)"
+        );
+    }
+
+    #[test]
+    fn hex_formatting() {
+        let color = Color::new(128, 255, 90);
+
+        assert_eq!(format!("{color:x}"), "80ff5a");
+        assert_eq!(format!("{color:X}"), "80FF5A");
+        assert_eq!(format!("{color:#x}"), "0x80ff5a");
+        assert_eq!(format!("{:>8x}", Color::new(0, 3, 254)), "  0003fe");
+        assert_eq!(color.to_string(), "RGB (128, 255, 90) 0x80FF5A");
+        assert_eq!(Model::from(color).to_string(), "rgb(128, 255, 90)");
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!("#80ff5a".parse(), Ok(Color::new(128, 255, 90)));
+        assert_eq!("#80FF5A".parse(), Ok(Color::new(128, 255, 90)));
+        assert_eq!("#f80".parse(), Ok(Color::new(255, 136, 0)));
+        assert_eq!(" rgb(0, 128,128) ".parse(), Ok(Color::new(0, 128, 128)));
+        assert_eq!("Orange".parse(), Ok(Color::new(255, 165, 0)));
+        assert_eq!("lime".parse::<Color>().unwrap().name(), Some("lime"));
+
+        for (input, error) in [
+            ("#12", ParseColorError::InvalidHex("#12".into())),
+            ("#12345g", ParseColorError::InvalidHex("#12345g".into())),
+            ("rgb(1, 2)", ParseColorError::InvalidRgb("rgb(1, 2)".into())),
+            (
+                "rgb(1, 2, 256)",
+                ParseColorError::InvalidRgb("rgb(1, 2, 256)".into()),
+            ),
+            ("mauve", ParseColorError::UnknownName("mauve".into())),
+        ] {
+            assert_eq!(input.parse::<Color>(), Err(error));
+        }
+    }
+
+    #[test]
+    fn parsing_round_trips_formatting() {
+        for color in sample() {
+            assert_eq!(format!("#{color:x}").parse(), Ok(color));
+            assert_eq!(Model::from(color).to_string().parse(), Ok(color));
+        }
+    }
+}
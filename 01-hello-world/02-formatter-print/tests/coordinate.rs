@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod coordinate_tests {
+    use formatted_print::coordinate::{Coordinate, CoordinateError};
+
+    fn coordinate(lat: f64, lon: f64) -> Coordinate {
+        Coordinate::new(lat, lon).unwrap()
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "{actual} is not within {tolerance} of {expected}"
+        );
+    }
+
+    #[test]
+    fn validates_ranges() {
+        assert!(Coordinate::new(90.0, -180.0).is_ok());
+        assert_eq!(
+            Coordinate::new(90.5, 0.0),
+            Err(CoordinateError::InvalidLatitude(90.5))
+        );
+        assert_eq!(
+            Coordinate::new(0.0, 181.0),
+            Err(CoordinateError::InvalidLongitude(181.0))
+        );
+        assert!(Coordinate::new(f64::NAN, 0.0).is_err());
+        assert!(Coordinate::new(0.0, f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn formatting() {
+        let dublin = coordinate(53.347778, -6.259722);
+        let sydney = coordinate(-33.8688, 151.2093);
+
+        assert_eq!(dublin.to_string(), "53.3478°N 6.2597°W");
+        assert_eq!(format!("{dublin:.2}"), "53.35°N 6.26°W");
+        assert_eq!(format!("{dublin:#}"), "53°20'52\"N 6°15'35\"W");
+        assert_eq!(format!("{dublin:#.2}"), "53°20'52.00\"N 6°15'35.00\"W");
+        assert_eq!(format!("{sydney:.1}"), "33.9°S 151.2°E");
+        // 59.9999 seconds rounds up to the next minute, not to 60 seconds
+        assert_eq!(
+            format!("{:#}", coordinate(10.0 + 59.9999 / 3600.0, 0.0)),
+            "10°1'0\"N 0°0'0\"E"
+        );
+    }
+
+    #[test]
+    fn large_precisions() {
+        let edge = coordinate(-90.0, 180.0);
+        let dublin = coordinate(53.347778, -6.259722);
+
+        // past 9 places, the seconds are padded with 0s
+        assert_eq!(
+            format!("{edge:#.12}"),
+            "90°0'0.000000000000\"S 180°0'0.000000000000\"E"
+        );
+        assert_eq!(
+            format!("{dublin:#.9}"),
+            "53°20'52.000800000\"N 6°15'34.999200000\"W"
+        );
+        assert_eq!(
+            format!("{dublin:#.16}"),
+            "53°20'52.0008000000000000\"N 6°15'34.9992000000000000\"W"
+        );
+
+        let wide = format!("{dublin:#.20}");
+
+        assert!(
+            wide.starts_with("53°20'52.00080000000000000000\"N"),
+            "{wide}"
+        );
+        assert_eq!(
+            format!("{edge:.20}"),
+            format!("{:.20}°S {:.20}°E", 90.0, 180.0)
+        );
+    }
+
+    #[test]
+    fn parsing() {
+        let dublin = coordinate(53.347778, -6.259722);
+
+        assert_eq!("53.347778, -6.259722".parse(), Ok(dublin));
+        assert_eq!("53.347778N 6.259722W".parse(), Ok(dublin));
+        assert_eq!(
+            "33°30'S 151°15′30″E".parse(),
+            Ok(coordinate(-33.5, 151.0 + 15.5 / 60.0))
+        );
+        assert_eq!("-0.5, 0".parse::<Coordinate>().map(|c| c.lat()), Ok(-0.5));
+
+        for invalid in [
+            "",
+            "53",
+            "53, 6, 7",
+            "10°E 20°N",
+            "53°61'N 6°W",
+            "-53°S 6°W",
+            "north",
+        ] {
+            assert_eq!(
+                invalid.parse::<Coordinate>(),
+                Err(CoordinateError::InvalidFormat(invalid.into()))
+            );
+        }
+
+        assert_eq!(
+            "91°N 0°E".parse::<Coordinate>(),
+            Err(CoordinateError::InvalidLatitude(91.0))
+        );
+    }
+
+    #[test]
+    fn parsing_round_trips_formatting() {
+        for (lat, lon) in [(53.347778, -6.259722), (-33.8688, 151.2093), (0.0, -180.0)] {
+            let coordinate = coordinate(lat, lon);
+
+            for formatted in [format!("{coordinate:.6}"), format!("{coordinate:#.3}")] {
+                let parsed: Coordinate = formatted.parse().unwrap();
+
+                assert_close(parsed.lat(), lat, 1e-6);
+                assert_close(parsed.lon(), lon, 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn distance_and_bearing() {
+        let london = coordinate(51.5074, -0.1278);
+        let paris = coordinate(48.8566, 2.3522);
+        let north_pole = coordinate(90.0, 0.0);
+
+        assert_close(london.distance_to(&paris), 343.6, 0.5);
+        assert_close(paris.distance_to(&london), london.distance_to(&paris), 1e-9);
+        assert_close(london.distance_to(&london), 0.0, 1e-9);
+        assert_close(london.bearing_to(&paris), 148.1, 0.1);
+        assert_close(london.bearing_to(&north_pole), 0.0, 1e-9);
+        assert_close(
+            coordinate(0.0, 0.0).bearing_to(&coordinate(0.0, -10.0)),
+            270.0,
+            1e-9,
+        );
+        // a quarter of the way around the equator
+        assert_close(
+            coordinate(0.0, 0.0).distance_to(&coordinate(0.0, 90.0)),
+            std::f64::consts::FRAC_PI_2 * 6371.0,
+            1e-6,
+        );
+    }
+}
@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod log_tests {
+    use formatted_print::log::{
+        format_time, FileSink, Filter, Level, Logger, MemorySink, Record, Sink,
+    };
+    use formatted_print::{debug, error, info, trace, warn};
+    use std::fs;
+    use std::sync::Arc;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    fn memory_logger(filter: &str) -> (Logger, Arc<MemorySink>) {
+        let memory = Arc::new(MemorySink::new());
+        let logger = Logger::new(filter.parse().unwrap(), Arc::clone(&memory));
+
+        (logger.with_timestamps(false), memory)
+    }
+
+    #[test]
+    fn levels() {
+        assert!(Level::Error < Level::Warn);
+        assert!(Level::Debug < Level::Trace);
+        assert_eq!("WARN".parse(), Ok(Level::Warn));
+        assert_eq!("trace".parse(), Ok(Level::Trace));
+        assert!("off".parse::<Level>().is_err());
+        assert_eq!(format!("[{:<5}]", Level::Info), "[INFO ]");
+    }
+
+    #[test]
+    fn macros_log_the_calling_module() {
+        let (logger, memory) = memory_logger("trace");
+        let x = 42;
+
+        error!(logger: logger, "x is {x}");
+        warn!(logger: logger, "x is {}", x + 1);
+        info!(logger: logger, "info");
+        debug!(logger: logger, "debug");
+        trace!(logger: logger, "trace");
+
+        assert_eq!(
+            memory.take(),
+            [
+                "ERROR log::log_tests: x is 42",
+                "WARN  log::log_tests: x is 43",
+                "INFO  log::log_tests: info",
+                "DEBUG log::log_tests: debug",
+                "TRACE log::log_tests: trace",
+            ]
+        );
+        assert!(memory.lines().is_empty());
+    }
+
+    #[test]
+    fn filtered_messages_are_dropped() {
+        let (logger, memory) = memory_logger("warn");
+
+        info!(logger: logger, "hidden");
+        warn!(logger: logger, "shown");
+
+        assert_eq!(memory.lines(), ["WARN  log::log_tests: shown"]);
+
+        let (logger, memory) = memory_logger("off");
+
+        error!(logger: logger, "hidden");
+
+        assert!(memory.lines().is_empty());
+    }
+
+    #[test]
+    fn module_overrides() {
+        let filter: Filter = "info, app::db=trace, app::db::pool=off".parse().unwrap();
+
+        assert!(filter.enabled(Level::Info, "app"));
+        assert!(!filter.enabled(Level::Debug, "app::net"));
+        assert!(filter.enabled(Level::Trace, "app::db"));
+        assert!(filter.enabled(Level::Trace, "app::db::query"));
+        assert!(!filter.enabled(Level::Error, "app::db::pool"));
+        // only whole module names match
+        assert!(!filter.enabled(Level::Debug, "app::dbx"));
+
+        let filter = Filter::off().with_module("app", Some(Level::Debug));
+
+        assert!(filter.enabled(Level::Debug, "app::net"));
+        assert!(!filter.enabled(Level::Error, "other"));
+    }
+
+    #[test]
+    fn invalid_filters() {
+        assert_eq!("".parse(), Ok(Filter::new(Level::Warn)));
+        assert!("loud".parse::<Filter>().is_err());
+        assert!("info,app=loud".parse::<Filter>().is_err());
+        assert!("info,=debug".parse::<Filter>().is_err());
+        assert_eq!(
+            "loud".parse::<Filter>().unwrap_err().to_string(),
+            "invalid log filter 'loud', expected e.g. info,module=debug"
+        );
+    }
+
+    #[test]
+    fn timestamps() {
+        assert_eq!(format_time(UNIX_EPOCH), "1970-01-01T00:00:00.000Z");
+        assert_eq!(
+            format_time(UNIX_EPOCH + Duration::from_millis(951_782_400_123)),
+            "2000-02-29T00:00:00.123Z"
+        );
+        assert_eq!(
+            format_time(UNIX_EPOCH + Duration::from_secs(1_792_322_102)),
+            "2026-10-18T11:15:02.000Z"
+        );
+
+        let record = Record {
+            level: Level::Warn,
+            target: "primitives",
+            args: format_args!("{} is not an index", "\"x\""),
+            time: Some(UNIX_EPOCH),
+        };
+
+        assert_eq!(
+            record.to_string(),
+            "1970-01-01T00:00:00.000Z WARN  primitives: \"x\" is not an index"
+        );
+    }
+
+    #[test]
+    fn file_sink_appends() {
+        let path = std::env::temp_dir().join(format!("rbe-log-{}.log", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        for message in ["first", "second"] {
+            let sink = FileSink::create(&path).unwrap();
+            let logger = Logger::new(Filter::new(Level::Info), sink).with_timestamps(false);
+
+            info!(logger: logger, "{message}");
+            logger.flush();
+        }
+
+        let contents = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(
+            contents,
+            "INFO  log::log_tests: first\nINFO  log::log_tests: second\n"
+        );
+
+        // any sink can be shared or boxed
+        let boxed: Box<dyn Sink> = Box::new(MemorySink::new());
+        let _logger = Logger::new(Filter::off(), boxed);
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod pretty_tests {
+    use formatted_print::pretty::{Doc, ToDoc};
+    use std::collections::BTreeMap;
+
+    fn numbers(range: std::ops::RangeInclusive<i32>) -> Doc {
+        Doc::list(range.map(|n| n.to_doc()))
+    }
+
+    #[test]
+    fn groups_fit_or_break() {
+        let doc = numbers(1..=5);
+
+        assert_eq!(doc.pretty(15).to_string(), "[1, 2, 3, 4, 5]");
+        assert_eq!(
+            doc.pretty(14).to_string(),
+            "[\n    1,\n    2,\n    3,\n    4,\n    5,\n]"
+        );
+        assert_eq!(Doc::list([]).pretty(0).to_string(), "[]");
+    }
+
+    #[test]
+    fn line_softline_and_nest() {
+        let doc = (Doc::text("let x =") + (Doc::line() + Doc::text("value")).nest(2)).group();
+
+        assert_eq!(doc.pretty(20).to_string(), "let x = value");
+        assert_eq!(doc.pretty(10).to_string(), "let x =\n  value");
+
+        let doc = (Doc::text("f(") + Doc::softline() + Doc::text("arg") + Doc::text(")")).group();
+
+        assert_eq!(doc.pretty(20).to_string(), "f(arg)");
+        assert_eq!(doc.pretty(4).to_string(), "f(\narg)");
+    }
+
+    #[test]
+    fn hardline_breaks_its_group() {
+        let doc =
+            (Doc::text("a") + Doc::line() + Doc::text("b") + Doc::hardline() + Doc::text("c"))
+                .group();
+
+        assert_eq!(doc.pretty(80).to_string(), "a\nb\nc");
+    }
+
+    #[test]
+    fn text_after_a_group_counts_towards_fitting() {
+        // "[1, 2]" fits in 8 columns, but not with the "!!!" after it
+        let doc = numbers(1..=2) + Doc::text("!!!");
+
+        assert_eq!(doc.pretty(9).to_string(), "[1, 2]!!!");
+        assert_eq!(doc.pretty(8).to_string(), "[\n    1,\n    2,\n]!!!");
+    }
+
+    #[test]
+    fn nested_groups_only_break_when_needed() {
+        let doc = Doc::record(
+            "Rectangle",
+            [
+                (
+                    "top_left",
+                    Doc::record("Point", [("x", 0.to_doc()), ("y", 0.to_doc())]),
+                ),
+                (
+                    "size",
+                    Doc::tuple_struct("Size", [30.to_doc(), 40.to_doc()]),
+                ),
+            ],
+        );
+
+        assert_eq!(
+            doc.pretty(80).to_string(),
+            "Rectangle { top_left: Point { x: 0, y: 0 }, size: Size(30, 40) }"
+        );
+        assert_eq!(
+            doc.pretty(40).to_string(),
+            "\
+Rectangle {
+    top_left: Point { x: 0, y: 0 },
+    size: Size(30, 40),
+}"
+        );
+        assert_eq!(format!("{doc:20}"), doc.pretty(20).to_string());
+        assert_eq!(format!("{doc}"), doc.pretty(80).to_string());
+    }
+
+    #[test]
+    fn to_doc_for_std_types() {
+        let map: BTreeMap<&str, Vec<Option<char>>> =
+            BTreeMap::from([("a", vec![Some('x'), None]), ("b", vec![])]);
+
+        assert_eq!(
+            map.to_doc().to_string(),
+            "{\"a\": [Some('x'), None], \"b\": []}"
+        );
+        assert_eq!(
+            format!("{:20}", map.to_doc()),
+            "{\n    \"a\": [\n        Some('x'),\n        None,\n    ],\n    \"b\": [],\n}"
+        );
+        assert_eq!((1, "one").to_doc().to_string(), "(1, \"one\")");
+        assert_eq!(Ok::<_, String>(2.5).to_doc().to_string(), "Ok(2.5)");
+    }
+}
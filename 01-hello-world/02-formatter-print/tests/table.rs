@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod table_tests {
+    use formatted_print::table::{display_width, Align, Table};
+
+    fn cities() -> Table {
+        let mut table = Table::new(["city", "lat", "lon"]);
+
+        table.push_row(["Dublin", "53.3478", "-6.2597"]);
+        table.push_row(["Oslo", "59.95", "10.75"]);
+
+        table
+    }
+
+    #[test]
+    fn plain_text() {
+        assert_eq!(
+            cities().to_string(),
+            "\
+city        lat      lon
+------  -------  -------
+Dublin  53.3478  -6.2597
+Oslo      59.95    10.75"
+        );
+        assert_eq!(Table::new(["a", "b"]).to_string(), "a  b\n-  -");
+    }
+
+    #[test]
+    fn formatter_flags() {
+        assert_eq!(
+            format!("{:*^8}", cities()),
+            "\
+**city**  *****lat  *****lon
+--------  --------  --------
+*Dublin*  *53.3478  *-6.2597
+**Oslo**  ***59.95  ***10.75"
+        );
+    }
+
+    #[test]
+    fn explicit_alignment_overrides_numbers() {
+        let table = cities()
+            .with_align(0, Align::Right)
+            .with_align(1, Align::Left)
+            .with_align(2, Align::Center);
+
+        assert_eq!(
+            table.to_string(),
+            "  city  lat        lon\n\
+             ------  -------  -------\n\
+             Dublin  53.3478  -6.2597\n\
+             \x20 Oslo  59.95     10.75"
+        );
+        assert!(cities().is_numeric(1));
+        assert!(!cities().is_numeric(0));
+    }
+
+    #[test]
+    fn unicode_widths() {
+        assert_eq!(display_width("Oslo"), 4);
+        assert_eq!(display_width("東京"), 4);
+        assert_eq!(display_width("São Paulo"), 9);
+        // "e" followed by a combining acute accent
+        assert_eq!(display_width("caf\u{65}\u{301}"), 4);
+
+        let mut table = Table::new(["city", "n"]);
+
+        table.push_row(["東京", "1"]);
+        table.push_row(["Oslo", "22"]);
+
+        assert_eq!(table.to_string(), "city   n\n----  --\n東京   1\nOslo  22");
+    }
+
+    #[test]
+    fn ragged_rows() {
+        let mut table = Table::new(["a"]);
+
+        table.push_row(["1", "extra"]);
+        table.push_row(Vec::<String>::new());
+
+        assert_eq!(table.columns(), 2);
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.to_string(), "a\n-  -----\n1  extra\n");
+    }
+
+    #[test]
+    fn multi_line_cells() {
+        let mut table = Table::new(["name", "n"]);
+
+        table.push_row(["two\nlines", "1"]);
+        table.push_row(["cr\r\nlf", "22"]);
+
+        assert_eq!(
+            table.to_string(),
+            "name    n\n-----  --\ntwo     1\nlines\ncr     22\nlf"
+        );
+    }
+
+    #[test]
+    fn only_finite_numbers_are_numeric() {
+        let mut table = Table::new(["a", "b", "c"]);
+
+        table.push_row(["NaN", "inf", "1e3"]);
+        table.push_row(["1", "2", "-0.5"]);
+
+        assert!(!table.is_numeric(0));
+        assert!(!table.is_numeric(1));
+        assert!(table.is_numeric(2));
+        assert_eq!(table.align(0, Align::Left), Align::Left);
+    }
+
+    #[test]
+    fn markdown() {
+        let mut table = cities().with_align(0, Align::Center);
+
+        table.push_row(["a|b", "", ""]);
+
+        assert_eq!(
+            table.markdown().to_string(),
+            "\
+|  city  |     lat |     lon |
+| :----: | ------: | ------: |
+| Dublin | 53.3478 | -6.2597 |
+|  Oslo  |   59.95 |   10.75 |
+|  a\\|b  |         |         |"
+        );
+
+        // line breaks inside a cell would end the row
+        let mut notes = Table::new(["note"]);
+
+        notes.push_row(["two\nlines"]);
+        notes.push_row(["cr\r\nlf"]);
+
+        assert_eq!(
+            notes.markdown().to_string(),
+            "| note         |\n| :----------- |\n| two<br>lines |\n| cr<br>lf     |"
+        );
+    }
+
+    #[test]
+    fn csv() {
+        let mut table = Table::new(["name", "notes"]);
+
+        table.push_row(["Doe, Jane", "said \"hi\""]);
+        table.push_row(["plain", ""]);
+
+        assert_eq!(
+            table.csv().to_string(),
+            "name,notes\r\n\"Doe, Jane\",\"said \"\"hi\"\"\"\r\nplain,\r\n"
+        );
+    }
+}
@@ -0,0 +1,977 @@
+// Integers with as many digits as they need, for when `u128` isn't enough,
+// grown from `addition_and_subtraction` and `underscored_integers`.
+//
+// `BigUint` is never negative, and `BigInt` adds a sign. Both work with the
+// usual operators, on values or references, and can be built from any
+// primitive integer or parsed from a string in bases 2 to 36:
+//
+//     let x = BigUint::from(u128::MAX) + 1_u32;
+//     let y: BigInt = "-0x_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff".parse()?;
+//
+//     println!("{x}");       // 340282366920938463463374607431768211456
+//     println!("{:#x}", y);  // -0xffffffffffffffffffffffffffffffff
+//
+// Like the primitive integers, dividing by zero and subtracting a larger
+// `BigUint` from a smaller one panic, and division rounds towards zero.
+use std::cmp::Ordering;
+use std::error;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use std::str::FromStr;
+
+// the digits are base 2^32, least significant first, with no zeros at the end,
+// so zero has no digits at all
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    digits: Vec<u32>,
+}
+
+// zero is never negative, so each value only has one representation
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: BigUint,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseBigIntError {
+    Empty,
+    InvalidDigit(char),
+}
+
+impl fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseBigIntError::Empty => write!(f, "cannot parse integer from empty string"),
+            ParseBigIntError::InvalidDigit(c) => write!(f, "invalid digit {c:?} found in string"),
+        }
+    }
+}
+
+impl error::Error for ParseBigIntError {}
+
+impl BigUint {
+    pub fn zero() -> BigUint {
+        BigUint::default()
+    }
+
+    pub fn one() -> BigUint {
+        BigUint { digits: vec![1] }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    // the number of bits needed to hold the value
+    pub fn bits(&self) -> u64 {
+        match self.digits.last() {
+            Some(last) => self.digits.len() as u64 * 32 - u64::from(last.leading_zeros()),
+            None => 0,
+        }
+    }
+
+    // the value, if it fits
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.digits.len() > 4 {
+            return None;
+        }
+
+        Some(
+            self.digits
+                .iter()
+                .rev()
+                .fold(0, |n, digit| (n << 32) | u128::from(*digit)),
+        )
+    }
+
+    // the sign and magnitude, for formatting
+    fn parts(&self) -> (bool, &BigUint) {
+        (true, self)
+    }
+
+    fn from_digits(mut digits: Vec<u32>) -> BigUint {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        BigUint { digits }
+    }
+
+    // self * m + a, in place
+    fn mul_add_small(&mut self, m: u32, a: u32) {
+        let mut carry = u64::from(a);
+
+        for digit in self.digits.iter_mut() {
+            let t = u64::from(*digit) * u64::from(m) + carry;
+
+            *digit = t as u32;
+            carry = t >> 32;
+        }
+
+        if carry > 0 {
+            self.digits.push(carry as u32);
+        }
+    }
+
+    fn div_rem_small(&self, divisor: u32) -> (BigUint, u32) {
+        assert!(divisor != 0, "attempt to divide by zero");
+
+        let mut quotient = vec![0; self.digits.len()];
+        let mut rem = 0_u64;
+
+        for (i, digit) in self.digits.iter().enumerate().rev() {
+            let t = (rem << 32) | u64::from(*digit);
+
+            quotient[i] = (t / u64::from(divisor)) as u32;
+            rem = t % u64::from(divisor);
+        }
+
+        (BigUint::from_digits(quotient), rem as u32)
+    }
+
+    // long division, from Knuth's "The Art of Computer Programming" volume 2,
+    // algorithm D, as written up in "Hacker's Delight"
+    pub fn div_rem(&self, divisor: &BigUint) -> (BigUint, BigUint) {
+        assert!(!divisor.is_zero(), "attempt to divide by zero");
+
+        if self < divisor {
+            return (BigUint::zero(), self.clone());
+        }
+
+        if let [divisor] = divisor.digits[..] {
+            let (quotient, rem) = self.div_rem_small(divisor);
+
+            return (quotient, BigUint::from(rem));
+        }
+
+        // shift both numbers so the divisor's top digit has its high bit set,
+        // which keeps the estimate of each quotient digit off by at most 2
+        let shift = divisor.digits.last().unwrap().leading_zeros();
+        let v = shl_digits(&divisor.digits, shift);
+        let mut u = shl_digits(&self.digits, shift);
+
+        u.push(0);
+
+        let n = v.len();
+        let base = 1_u64 << 32;
+        let mut quotient = vec![0; u.len() - n];
+
+        for j in (0..quotient.len()).rev() {
+            let top = (u64::from(u[j + n]) << 32) | u64::from(u[j + n - 1]);
+            let mut qhat = top / u64::from(v[n - 1]);
+            let mut rhat = top % u64::from(v[n - 1]);
+
+            while qhat >= base
+                || qhat * u64::from(v[n - 2]) > (rhat << 32) + u64::from(u[j + n - 2])
+            {
+                qhat -= 1;
+                rhat += u64::from(v[n - 1]);
+
+                if rhat >= base {
+                    break;
+                }
+            }
+
+            // u[j..=j + n] -= qhat * v
+            let mut borrow = 0_i64;
+
+            for i in 0..n {
+                let p = qhat * u64::from(v[i]);
+                let t = i64::from(u[i + j]) - borrow - (p & 0xFFFF_FFFF) as i64;
+
+                u[i + j] = t as u32;
+                borrow = (p >> 32) as i64 - (t >> 32);
+            }
+
+            let t = i64::from(u[j + n]) - borrow;
+
+            u[j + n] = t as u32;
+
+            // qhat was still one too big, so add v back
+            if t < 0 {
+                qhat -= 1;
+
+                let mut carry = 0_u64;
+
+                for i in 0..n {
+                    let t = u64::from(u[i + j]) + u64::from(v[i]) + carry;
+
+                    u[i + j] = t as u32;
+                    carry = t >> 32;
+                }
+
+                u[j + n] = u[j + n].wrapping_add(carry as u32);
+            }
+
+            quotient[j] = qhat as u32;
+        }
+
+        u.truncate(n);
+
+        (
+            BigUint::from_digits(quotient),
+            BigUint::from_digits(shr_digits(&u, shift)),
+        )
+    }
+
+    pub fn checked_sub(&self, rhs: &BigUint) -> Option<BigUint> {
+        if self < rhs {
+            return None;
+        }
+
+        let mut digits = self.digits.clone();
+        let mut borrow = 0;
+
+        for (i, digit) in digits.iter_mut().enumerate() {
+            let rhs = u64::from(rhs.digits.get(i).copied().unwrap_or(0)) + borrow;
+            let (t, overflowed) = u64::from(*digit).overflowing_sub(rhs);
+
+            *digit = t as u32;
+            borrow = u64::from(overflowed);
+        }
+
+        Some(BigUint::from_digits(digits))
+    }
+
+    pub fn pow(&self, mut exp: u32) -> BigUint {
+        let mut base = self.clone();
+        let mut result = BigUint::one();
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = &result * &base;
+            }
+
+            exp >>= 1;
+
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+
+        result
+    }
+
+    // the greatest common divisor, using Euclid's algorithm
+    pub fn gcd(&self, other: &BigUint) -> BigUint {
+        let (mut a, mut b) = (self.clone(), other.clone());
+
+        while !b.is_zero() {
+            let rem = &a % &b;
+
+            a = b;
+            b = rem;
+        }
+
+        a
+    }
+
+    // `s` in base `radix`, with `_` allowed between digits as in Rust
+    // literals. Panics if radix isn't from 2 to 36, like `u32::from_str_radix`
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<BigUint, ParseBigIntError> {
+        BigUint::parse_digits(s.strip_prefix('+').unwrap_or(s), radix)
+    }
+
+    // checks the radix for both `from_str_radix`s - with a radix of 0 or 1,
+    // `chunk` would never fill up
+    fn parse_digits(s: &str, radix: u32) -> Result<BigUint, ParseBigIntError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "from_str_radix: radix must lie in the range `[2, 36]` - found {radix}"
+        );
+
+        match s.chars().next() {
+            None => return Err(ParseBigIntError::Empty),
+            Some('_') => return Err(ParseBigIntError::InvalidDigit('_')),
+            Some(_) => {}
+        }
+
+        // collect as many digits as fit in a u32 before touching the big
+        // number, rather than multiplying it by radix for every digit
+        let (chunk_base, chunk_len) = chunk(radix);
+        let mut n = BigUint::zero();
+        let (mut value, mut len) = (0, 0);
+
+        for c in s.chars().filter(|c| *c != '_') {
+            let digit = c.to_digit(radix).ok_or(ParseBigIntError::InvalidDigit(c))?;
+
+            value = value * radix + digit;
+            len += 1;
+
+            if len == chunk_len {
+                n.mul_add_small(chunk_base, value);
+                (value, len) = (0, 0);
+            }
+        }
+
+        if len > 0 {
+            n.mul_add_small(radix.pow(len), value);
+        }
+
+        Ok(n)
+    }
+
+    // the digits in base `radix`, lowercase. Panics if radix isn't from 2 to
+    // 36
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        assert!(
+            (2..=36).contains(&radix),
+            "to_str_radix: radix must lie in the range `[2, 36]` - found {radix}"
+        );
+
+        if self.is_zero() {
+            return "0".to_string();
+        }
+
+        let (chunk_base, chunk_len) = chunk(radix);
+        let mut chunks = vec![];
+        let mut n = self.clone();
+
+        while !n.is_zero() {
+            let (quotient, rem) = n.div_rem_small(chunk_base);
+
+            chunks.push(rem);
+            n = quotient;
+        }
+
+        let mut s = String::new();
+
+        for (i, chunk) in chunks.iter().rev().enumerate() {
+            let mut digits = vec![];
+            let mut chunk = *chunk;
+
+            while chunk > 0 {
+                digits.push(char::from_digit(chunk % radix, radix).unwrap());
+                chunk /= radix;
+            }
+
+            // every chunk but the first is padded with zeros
+            if i > 0 {
+                digits.resize(chunk_len as usize, '0');
+            }
+
+            s.extend(digits.iter().rev());
+        }
+
+        s
+    }
+}
+
+// the largest power of `radix` that fits in a u32, and its exponent
+fn chunk(radix: u32) -> (u32, u32) {
+    let mut base = radix;
+    let mut len = 1;
+
+    while let Some(next) = base.checked_mul(radix) {
+        base = next;
+        len += 1;
+    }
+
+    (base, len)
+}
+
+fn shl_digits(digits: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return digits.to_vec();
+    }
+
+    let mut shifted: Vec<u32> = digits
+        .iter()
+        .scan(0, |carry, digit| {
+            let t = (digit << shift) | *carry;
+
+            *carry = digit >> (32 - shift);
+
+            Some(t)
+        })
+        .collect();
+    let carry = digits.last().map_or(0, |last| last >> (32 - shift));
+
+    if carry > 0 {
+        shifted.push(carry);
+    }
+
+    shifted
+}
+
+fn shr_digits(digits: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return digits.to_vec();
+    }
+
+    (0..digits.len())
+        .map(|i| {
+            let high = digits.get(i + 1).map_or(0, |next| next << (32 - shift));
+
+            (digits[i] >> shift) | high
+        })
+        .collect()
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &BigUint) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUint {
+    // more digits is bigger, otherwise compare from the most significant digit
+    fn cmp(&self, other: &BigUint) -> Ordering {
+        self.digits
+            .len()
+            .cmp(&other.digits.len())
+            .then_with(|| self.digits.iter().rev().cmp(other.digits.iter().rev()))
+    }
+}
+
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: &BigUint) -> BigUint {
+        let (long, short) = if self.digits.len() >= rhs.digits.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut digits = Vec::with_capacity(long.digits.len() + 1);
+        let mut carry = 0;
+
+        for (i, digit) in long.digits.iter().enumerate() {
+            let t =
+                u64::from(*digit) + u64::from(short.digits.get(i).copied().unwrap_or(0)) + carry;
+
+            digits.push(t as u32);
+            carry = t >> 32;
+        }
+
+        if carry > 0 {
+            digits.push(carry as u32);
+        }
+
+        BigUint::from_digits(digits)
+    }
+}
+
+impl Sub<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn sub(self, rhs: &BigUint) -> BigUint {
+        self.checked_sub(rhs)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    // long multiplication. The biggest each step can get is
+    // (2^32 - 1) + (2^32 - 1)^2 + (2^32 - 1), which just fits in a u64
+    fn mul(self, rhs: &BigUint) -> BigUint {
+        if self.is_zero() || rhs.is_zero() {
+            return BigUint::zero();
+        }
+
+        let mut digits = vec![0_u32; self.digits.len() + rhs.digits.len()];
+
+        for (i, a) in self.digits.iter().enumerate() {
+            let mut carry = 0_u64;
+
+            for (j, b) in rhs.digits.iter().enumerate() {
+                let t = u64::from(digits[i + j]) + u64::from(*a) * u64::from(*b) + carry;
+
+                digits[i + j] = t as u32;
+                carry = t >> 32;
+            }
+
+            digits[i + rhs.digits.len()] = carry as u32;
+        }
+
+        BigUint::from_digits(digits)
+    }
+}
+
+impl Div<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn div(self, rhs: &BigUint) -> BigUint {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn rem(self, rhs: &BigUint) -> BigUint {
+        self.div_rem(rhs).1
+    }
+}
+
+impl BigInt {
+    pub fn zero() -> BigInt {
+        BigInt::default()
+    }
+
+    pub fn one() -> BigInt {
+        BigInt::from(BigUint::one())
+    }
+
+    fn from_parts(negative: bool, magnitude: BigUint) -> BigInt {
+        BigInt {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    fn parts(&self) -> (bool, &BigUint) {
+        (!self.negative, &self.magnitude)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+
+    pub fn abs(&self) -> BigInt {
+        BigInt::from(self.magnitude.clone())
+    }
+
+    // -1, 0 or 1
+    pub fn signum(&self) -> i32 {
+        match (self.negative, self.is_zero()) {
+            (true, _) => -1,
+            (false, true) => 0,
+            (false, false) => 1,
+        }
+    }
+
+    pub fn to_i128(&self) -> Option<i128> {
+        let magnitude = self.magnitude.to_u128()?;
+
+        if self.negative {
+            0_i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+
+    pub fn div_rem(&self, rhs: &BigInt) -> (BigInt, BigInt) {
+        let (quotient, rem) = self.magnitude.div_rem(&rhs.magnitude);
+
+        // the quotient rounds towards zero, so the remainder has the sign of
+        // self, e.g. -7 / 2 = -3 and -7 % 2 = -1
+        (
+            BigInt::from_parts(self.negative != rhs.negative, quotient),
+            BigInt::from_parts(self.negative, rem),
+        )
+    }
+
+    pub fn pow(&self, exp: u32) -> BigInt {
+        BigInt::from_parts(self.negative && exp % 2 == 1, self.magnitude.pow(exp))
+    }
+
+    // always positive, or zero if both are zero
+    pub fn gcd(&self, other: &BigInt) -> BigInt {
+        BigInt::from(self.magnitude.gcd(&other.magnitude))
+    }
+
+    // an optional sign, then digits as in `BigUint::from_str_radix`. Panics if
+    // radix isn't from 2 to 36
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<BigInt, ParseBigIntError> {
+        let (negative, digits) = split_sign(s);
+        let magnitude = BigUint::parse_digits(digits, radix)?;
+
+        Ok(BigInt::from_parts(negative, magnitude))
+    }
+
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        let digits = self.magnitude.to_str_radix(radix);
+
+        if self.negative {
+            format!("-{digits}")
+        } else {
+            digits
+        }
+    }
+}
+
+impl From<BigUint> for BigInt {
+    fn from(magnitude: BigUint) -> BigInt {
+        BigInt::from_parts(false, magnitude)
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &BigInt) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &BigInt) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+impl Add<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: &BigInt) -> BigInt {
+        if self.negative == rhs.negative {
+            return BigInt::from_parts(self.negative, &self.magnitude + &rhs.magnitude);
+        }
+
+        // the signs differ, so the result takes the sign of the bigger
+        // magnitude
+        match self.magnitude.cmp(&rhs.magnitude) {
+            Ordering::Less => BigInt::from_parts(rhs.negative, &rhs.magnitude - &self.magnitude),
+            _ => BigInt::from_parts(self.negative, &self.magnitude - &rhs.magnitude),
+        }
+    }
+}
+
+impl Sub<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        self + &-rhs
+    }
+}
+
+impl Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_parts(
+            self.negative != rhs.negative,
+            &self.magnitude * &rhs.magnitude,
+        )
+    }
+}
+
+impl Div<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).1
+    }
+}
+
+// the operators above work on references, so these add the versions that
+// take values, or a mix of values and references, plus the `+=` forms. Any
+// primitive integer can also be used on the right
+macro_rules! forward_ops {
+    ($big: ty, $(($trait: ident, $method: ident)),+) => {
+        $(
+            impl $trait<$big> for $big {
+                type Output = $big;
+
+                fn $method(self, rhs: $big) -> $big {
+                    (&self).$method(&rhs)
+                }
+            }
+
+            impl $trait<&$big> for $big {
+                type Output = $big;
+
+                fn $method(self, rhs: &$big) -> $big {
+                    (&self).$method(rhs)
+                }
+            }
+
+            impl $trait<$big> for &$big {
+                type Output = $big;
+
+                fn $method(self, rhs: $big) -> $big {
+                    self.$method(&rhs)
+                }
+            }
+        )+
+    };
+}
+
+forward_ops!(
+    BigUint,
+    (Add, add),
+    (Sub, sub),
+    (Mul, mul),
+    (Div, div),
+    (Rem, rem)
+);
+forward_ops!(
+    BigInt,
+    (Add, add),
+    (Sub, sub),
+    (Mul, mul),
+    (Div, div),
+    (Rem, rem)
+);
+
+macro_rules! forward_assign_ops {
+    ($big: ty, $(($trait: ident, $method: ident, $op: ident)),+) => {
+        $(
+            impl $trait<&$big> for $big {
+                fn $method(&mut self, rhs: &$big) {
+                    *self = (&*self).$op(rhs);
+                }
+            }
+
+            impl $trait<$big> for $big {
+                fn $method(&mut self, rhs: $big) {
+                    *self = (&*self).$op(&rhs);
+                }
+            }
+        )+
+    };
+}
+
+forward_assign_ops!(
+    BigUint,
+    (AddAssign, add_assign, add),
+    (SubAssign, sub_assign, sub),
+    (MulAssign, mul_assign, mul)
+);
+forward_assign_ops!(
+    BigInt,
+    (AddAssign, add_assign, add),
+    (SubAssign, sub_assign, sub),
+    (MulAssign, mul_assign, mul)
+);
+
+macro_rules! impl_primitive_ops {
+    ($big: ty, $($t: ty),+) => {
+        $(
+            impl_primitive_ops!(@ops $big, $big, $t);
+            impl_primitive_ops!(@ops $big, &$big, $t);
+        )+
+    };
+    (@ops $big: ty, $lhs: ty, $t: ty) => {
+        impl Add<$t> for $lhs {
+            type Output = $big;
+
+            fn add(self, rhs: $t) -> $big {
+                self + <$big>::from(rhs)
+            }
+        }
+
+        impl Sub<$t> for $lhs {
+            type Output = $big;
+
+            fn sub(self, rhs: $t) -> $big {
+                self - <$big>::from(rhs)
+            }
+        }
+
+        impl Mul<$t> for $lhs {
+            type Output = $big;
+
+            fn mul(self, rhs: $t) -> $big {
+                self * <$big>::from(rhs)
+            }
+        }
+    };
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t: ty),+) => {
+        $(
+            impl From<$t> for BigUint {
+                fn from(n: $t) -> BigUint {
+                    let n = n as u128;
+
+                    BigUint::from_digits((0..4).map(|i| (n >> (32 * i)) as u32).collect())
+                }
+            }
+
+            impl From<$t> for BigInt {
+                fn from(n: $t) -> BigInt {
+                    BigInt::from(BigUint::from(n))
+                }
+            }
+        )+
+
+        impl_primitive_ops!(BigUint, $($t),+);
+    };
+}
+
+macro_rules! impl_from_signed {
+    ($($t: ty),+) => {
+        $(
+            impl From<$t> for BigInt {
+                fn from(n: $t) -> BigInt {
+                    BigInt::from_parts(n < 0, BigUint::from(n.unsigned_abs()))
+                }
+            }
+        )+
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_from_signed!(i8, i16, i32, i64, i128, isize);
+impl_primitive_ops!(BigInt, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl Sum for BigUint {
+    fn sum<I: Iterator<Item = BigUint>>(iter: I) -> BigUint {
+        iter.fold(BigUint::zero(), |sum, n| sum + n)
+    }
+}
+
+impl Product for BigUint {
+    fn product<I: Iterator<Item = BigUint>>(iter: I) -> BigUint {
+        iter.fold(BigUint::one(), |product, n| product * n)
+    }
+}
+
+impl Sum for BigInt {
+    fn sum<I: Iterator<Item = BigInt>>(iter: I) -> BigInt {
+        iter.fold(BigInt::zero(), |sum, n| sum + n)
+    }
+}
+
+impl Product for BigInt {
+    fn product<I: Iterator<Item = BigInt>>(iter: I) -> BigInt {
+        iter.fold(BigInt::one(), |product, n| product * n)
+    }
+}
+
+fn split_sign(s: &str) -> (bool, &str) {
+    match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    }
+}
+
+// a radix prefix as in Rust literals, e.g. 0xff or 0b1010, or decimal
+fn split_radix(s: &str) -> (&str, u32) {
+    for (prefix, radix) in [("0x", 16), ("0o", 8), ("0b", 2)] {
+        if let Some(rest) = s.strip_prefix(prefix) {
+            // allow `0x_ff`, like the compiler does
+            return (rest.trim_start_matches('_'), radix);
+        }
+    }
+
+    (s, 10)
+}
+
+// decimal, or hex, octal or binary with a 0x, 0o or 0b prefix. Underscores can
+// separate the digits
+impl FromStr for BigUint {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<BigUint, Self::Err> {
+        let (digits, radix) = split_radix(s.strip_prefix('+').unwrap_or(s));
+
+        BigUint::parse_digits(digits, radix)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<BigInt, Self::Err> {
+        let (negative, s) = split_sign(s);
+        let (digits, radix) = split_radix(s);
+        let magnitude = BigUint::parse_digits(digits, radix)?;
+
+        Ok(BigInt::from_parts(negative, magnitude))
+    }
+}
+
+// `pad_integral` handles the sign, the `+` and `0` flags, width and alignment,
+// so the big integers line up with the primitive ones
+fn fmt_radix(
+    f: &mut fmt::Formatter<'_>,
+    (is_nonnegative, magnitude): (bool, &BigUint),
+    radix: u32,
+    prefix: &str,
+) -> fmt::Result {
+    let digits = magnitude.to_str_radix(radix);
+
+    f.pad_integral(is_nonnegative, prefix, &digits)
+}
+
+macro_rules! impl_fmt {
+    ($($big: ty),+) => {
+        $(
+            impl fmt::Display for $big {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt_radix(f, self.parts(), 10, "")
+                }
+            }
+
+            // the digits rather than the internal representation, so a
+            // `Vec<BigUint>` is readable
+            impl fmt::Debug for $big {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(self, f)
+                }
+            }
+
+            impl fmt::LowerHex for $big {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt_radix(f, self.parts(), 16, "0x")
+                }
+            }
+
+            impl fmt::UpperHex for $big {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    let (is_nonnegative, magnitude) = self.parts();
+                    let digits = magnitude.to_str_radix(16).to_uppercase();
+
+                    f.pad_integral(is_nonnegative, "0x", &digits)
+                }
+            }
+
+            impl fmt::Octal for $big {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt_radix(f, self.parts(), 8, "0o")
+                }
+            }
+
+            impl fmt::Binary for $big {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt_radix(f, self.parts(), 2, "0b")
+                }
+            }
+        )+
+    };
+}
+
+impl_fmt!(BigUint, BigInt);
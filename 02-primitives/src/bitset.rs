@@ -0,0 +1,336 @@
+// A set of small numbers stored as bits, grown from `bitwise_operations`: the
+// number n is in the set if bit n is 1, so set operations are the bitwise
+// operators applied 64 numbers at a time.
+//
+//     let x = BitSet::from_iter([1, 2, 3]);
+//     let y = BitSet::from_iter([3, 4, 5]);
+//
+//     println!("{:?}", &x | &y);  // {1, 2, 3, 4, 5}
+//     println!("{:?}", &x & &y);  // {3}
+//     println!("{:b}", x);        // 1110
+//
+// The methods match `HashSet<u32>`'s, so it can replace one whose values are
+// small, and it iterates in order.
+//
+// A set has a capacity: the numbers from 0 up to, but not including, the
+// capacity are the ones it can hold without growing. `insert` grows it as
+// needed, and `!set` is every number below the capacity that isn't in the set.
+use std::fmt;
+use std::ops::{BitAnd, BitOr, BitXor, Not, Sub};
+
+const BITS: usize = u64::BITS as usize;
+
+// bits at or above `capacity` are always 0
+#[derive(Clone, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+    capacity: usize,
+}
+
+impl BitSet {
+    pub fn new() -> BitSet {
+        BitSet::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> BitSet {
+        BitSet {
+            words: vec![0; capacity.div_ceil(BITS)],
+            capacity,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn grow(&mut self, capacity: usize) {
+        if capacity > self.capacity {
+            self.words.resize(capacity.div_ceil(BITS), 0);
+            self.capacity = capacity;
+        }
+    }
+
+    // the word holding `value`, and the mask for its bit
+    fn position(value: u32) -> (usize, u64) {
+        let value = value as usize;
+
+        (value / BITS, 1 << (value % BITS))
+    }
+
+    // true if `value` wasn't already in the set
+    pub fn insert(&mut self, value: u32) -> bool {
+        self.grow(value as usize + 1);
+
+        let (word, mask) = BitSet::position(value);
+        let inserted = self.words[word] & mask == 0;
+
+        self.words[word] |= mask;
+
+        inserted
+    }
+
+    // true if `value` was in the set
+    pub fn remove(&mut self, value: u32) -> bool {
+        let (word, mask) = BitSet::position(value);
+
+        match self.words.get_mut(word) {
+            Some(bits) if *bits & mask != 0 => {
+                *bits &= !mask;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn contains(&self, value: &u32) -> bool {
+        let (word, mask) = BitSet::position(*value);
+
+        self.words.get(word).is_some_and(|bits| bits & mask != 0)
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    // the same as count_ones, for HashSet compatibility
+    pub fn len(&self) -> usize {
+        self.count_ones()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    pub fn iter(&self) -> Iter<'_> {
+        Iter::new(&self.words, &[], |a, _| a)
+    }
+
+    // the lazy versions of |, -, & and ^, which don't build a new set
+    pub fn union<'a>(&'a self, other: &'a BitSet) -> Iter<'a> {
+        Iter::new(&self.words, &other.words, |a, b| a | b)
+    }
+
+    pub fn difference<'a>(&'a self, other: &'a BitSet) -> Iter<'a> {
+        Iter::new(&self.words, &other.words, |a, b| a & !b)
+    }
+
+    pub fn intersection<'a>(&'a self, other: &'a BitSet) -> Iter<'a> {
+        Iter::new(&self.words, &other.words, |a, b| a & b)
+    }
+
+    pub fn symmetric_difference<'a>(&'a self, other: &'a BitSet) -> Iter<'a> {
+        Iter::new(&self.words, &other.words, |a, b| a ^ b)
+    }
+
+    pub fn is_subset(&self, other: &BitSet) -> bool {
+        self.difference(other).next().is_none()
+    }
+
+    pub fn is_superset(&self, other: &BitSet) -> bool {
+        other.is_subset(self)
+    }
+
+    pub fn is_disjoint(&self, other: &BitSet) -> bool {
+        self.intersection(other).next().is_none()
+    }
+
+    // a new set with the larger capacity of the two, combining them a word at
+    // a time
+    fn combine(&self, other: &BitSet, op: fn(u64, u64) -> u64) -> BitSet {
+        let mut set = BitSet::with_capacity(self.capacity.max(other.capacity));
+
+        for (i, word) in set.words.iter_mut().enumerate() {
+            let a = self.words.get(i).copied().unwrap_or(0);
+            let b = other.words.get(i).copied().unwrap_or(0);
+
+            *word = op(a, b);
+        }
+
+        set
+    }
+}
+
+// the set numbers in order. Each step jumps straight to the next 1 bit with
+// `trailing_zeros`, rather than testing every bit
+#[derive(Clone)]
+pub struct Iter<'a> {
+    a: &'a [u64],
+    b: &'a [u64],
+    op: fn(u64, u64) -> u64,
+    // the index of the word after `word`
+    index: usize,
+    // the bits of the current word that haven't been returned yet
+    word: u64,
+}
+
+impl<'a> Iter<'a> {
+    fn new(a: &'a [u64], b: &'a [u64], op: fn(u64, u64) -> u64) -> Iter<'a> {
+        Iter {
+            a,
+            b,
+            op,
+            index: 0,
+            word: 0,
+        }
+    }
+}
+
+impl Iterator for Iter<'_> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        while self.word == 0 {
+            if self.index >= self.a.len().max(self.b.len()) {
+                return None;
+            }
+
+            let a = self.a.get(self.index).copied().unwrap_or(0);
+            let b = self.b.get(self.index).copied().unwrap_or(0);
+
+            self.word = (self.op)(a, b);
+            self.index += 1;
+        }
+
+        let bit = self.word.trailing_zeros() as usize;
+
+        // clear the lowest 1 bit
+        self.word &= self.word - 1;
+
+        Some(((self.index - 1) * BITS + bit) as u32)
+    }
+}
+
+// like HashSet's iterators, printing the values that are left
+impl fmt::Debug for Iter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a> IntoIterator for &'a BitSet {
+    type Item = u32;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+impl FromIterator<u32> for BitSet {
+    fn from_iter<I: IntoIterator<Item = u32>>(iter: I) -> BitSet {
+        let mut set = BitSet::new();
+
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<u32> for BitSet {
+    fn extend<I: IntoIterator<Item = u32>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<const N: usize> From<[u32; N]> for BitSet {
+    fn from(values: [u32; N]) -> BitSet {
+        BitSet::from_iter(values)
+    }
+}
+
+// sets with the same numbers are equal, whatever their capacities
+impl PartialEq for BitSet {
+    fn eq(&self, other: &BitSet) -> bool {
+        self.symmetric_difference(other).next().is_none()
+    }
+}
+
+impl Eq for BitSet {}
+
+impl fmt::Debug for BitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+// one digit per number below the capacity, highest first, so {0, 2} with a
+// capacity of 4 is 0101 - the same as `{:b}` of the integer the bits make up
+impl fmt::Binary for BitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits: String = (0..self.capacity)
+            .rev()
+            .map(|i| match self.contains(&(i as u32)) {
+                true => '1',
+                false => '0',
+            })
+            .collect();
+        let digits = if digits.is_empty() { "0" } else { &digits };
+
+        f.pad_integral(true, "0b", digits)
+    }
+}
+
+macro_rules! impl_set_ops {
+    ($(($trait: ident, $method: ident, $op: expr)),+) => {
+        $(
+            impl $trait<&BitSet> for &BitSet {
+                type Output = BitSet;
+
+                fn $method(self, rhs: &BitSet) -> BitSet {
+                    self.combine(rhs, $op)
+                }
+            }
+
+            impl $trait for BitSet {
+                type Output = BitSet;
+
+                fn $method(self, rhs: BitSet) -> BitSet {
+                    self.combine(&rhs, $op)
+                }
+            }
+        )+
+    };
+}
+
+impl_set_ops!(
+    (BitAnd, bitand, |a, b| a & b),
+    (BitOr, bitor, |a, b| a | b),
+    (BitXor, bitxor, |a, b| a ^ b),
+    (Sub, sub, |a, b| a & !b)
+);
+
+// every number below the capacity that isn't in the set
+impl Not for &BitSet {
+    type Output = BitSet;
+
+    fn not(self) -> BitSet {
+        let mut set = self.combine(self, |a, _| !a);
+
+        // clear the bits past the capacity in the last word
+        if let Some(last) = set.words.last_mut() {
+            let used = set.capacity % BITS;
+
+            if used > 0 {
+                *last &= (1 << used) - 1;
+            }
+        }
+
+        set
+    }
+}
+
+impl Not for BitSet {
+    type Output = BitSet;
+
+    fn not(self) -> BitSet {
+        !&self
+    }
+}
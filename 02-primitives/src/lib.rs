@@ -0,0 +1,311 @@
+use formatted_print::warn;
+use rbe_registry::Chapter;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::mem;
+
+pub mod bigint;
+pub mod bitset;
+pub mod matrix;
+
+use bigint::{BigInt, BigUint};
+use matrix::Matrix;
+
+fn underscored_integers() {
+    let x = 20_000_000;
+    let y = 0.000_000_01;
+
+    println!("20_000_000 prints as {x}");
+    println!(" 0.000_000_01 prints as {y}");
+    println!();
+}
+
+fn isize_usize_for_indexing() {
+    let xs = [1, 2, 3, 4, 5];
+    let index: usize = 3; // <= index is a usize
+    let value = &xs[index];
+
+    println!("xs at index {index}: {value:?}");
+    println!();
+}
+
+fn addition_and_subtraction() {
+    println!("{}", 42u32);
+    println!("1 + 2 = {}", 1u32 + 2);
+    println!("1 - 2 = {}", 1i32 - 2);
+    println!();
+}
+
+fn division() {
+    println!("integer division: 2 / 3 = {}", 2 / 3);
+    println!("floating point division: 2.0 / 3.0 = {}", 2.0 / 3.0);
+    println!();
+}
+
+fn big_integers() {
+    // even u128 runs out of room
+    println!("u128::MAX: {}", u128::MAX);
+    println!("u128::MAX.checked_add(1): {:?}", u128::MAX.checked_add(1));
+
+    // bigint::BigUint grows instead
+    let max = BigUint::from(u128::MAX);
+
+    println!("u128::MAX + 1 as a BigUint: {}", &max + 1_u8);
+    println!("u128::MAX squared: {}", &max * &max);
+    println!("2^200: {}", BigUint::from(2_u8).pow(200));
+    println!();
+
+    // strings can have underscores and radix prefixes, like literals
+    let x: BigInt = "-1_000_000_000_000_000_000_000_000_000_000_000"
+        .parse()
+        .unwrap();
+    let y: BigUint = "0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff"
+        .parse()
+        .unwrap();
+    let seven = BigInt::from(7);
+
+    println!("{x} / 7 = {}", &x / &seven);
+    println!("{x} % 7 = {}", &x % &seven);
+    println!("{y:#x} = {y}");
+
+    let (a, b) = (BigUint::from(6_u8).pow(40), BigUint::from(4_u8).pow(30));
+
+    println!("gcd(6^40, 4^30) = {}", a.gcd(&b));
+    println!();
+}
+
+#[allow(clippy::nonminimal_bool)]
+fn boolean_logic() {
+    println!("AND with &&: {}", true && false);
+    println!("OR with ||: {}", true || false);
+    println!("NOT with !: {}", !true);
+    println!();
+}
+
+fn bitwise_operations() {
+    println!("1101 AND 1010: {:04b}", 0b1101 & 0b1010);
+    println!("1101 OR 1010: {:04b}", 0b1101 | 0b1010);
+    println!("1101 XOR 1010: {:04b}", 0b1101 ^ 0b1010);
+    println!("left shift: 1 << 4: {}", 1u32 << 4);
+    println!("right shift: 0x80 >> 4: {}", 0x80u32 >> 4);
+    println!();
+}
+
+fn destructuring_tuples() {
+    let tup = ("hey", 'o', 3);
+    let (x, y, z) = tup;
+
+    println!("tuple: {tup:?}\n x: {x}, y: {y}, z: {z}");
+    println!();
+}
+
+fn functions_returning_tuples() -> (i32, &'static str) {
+    let tup = (1, 'a', "foo");
+
+    println!("{tup:?}");
+
+    let (x, _, z) = tup;
+
+    (x, z)
+}
+
+fn single_value_tuples_with_comma() {
+    let tup = (1,);
+    #[allow(unused_parens)]
+    let not_tup = (1);
+
+    println!("tuple: {tup:?}");
+    println!("not tuple: {not_tup:?}");
+    println!();
+}
+
+fn tuple_exercise() {
+    // the 2x2 tuple struct from the exercise has grown into `matrix::Matrix`,
+    // which can be any size
+    let matrix = Matrix::new([[1.0, 1.1], [2.0, 2.2]]);
+    let transpose_matrix = matrix.transpose();
+
+    println!("matrix: {matrix:?}");
+    println!("{matrix:.1}\n");
+    println!("transpose matrix: {transpose_matrix:?}");
+    println!("{transpose_matrix:.1}\n");
+
+    // a 2x3 matrix multiplied by a 3x2 matrix is a 2x2 matrix
+    let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+    let b = a.transpose();
+
+    println!("{a}\n*\n{b}\n=\n{}\n", a * b);
+
+    let square = Matrix::new([[4.0, 7.0], [2.0, 6.0]]);
+
+    println!("determinant:\n{square}\n= {}\n", square.determinant());
+
+    if let Some(inverse) = square.inverse() {
+        println!("inverse:\n{inverse:.2}");
+        println!("square * inverse:\n{:.1}", square * inverse);
+    }
+
+    println!();
+}
+
+#[allow(clippy::needless_late_init)]
+fn array_definition_without_binding() {
+    let xs: [i8; 3];
+
+    // we can bind xs here
+    xs = [1, 2, 3];
+
+    println!("{xs:?}");
+    println!();
+}
+
+fn array_initialisation() {
+    let xs = [3; 5];
+
+    println!("{xs:?}");
+    println!();
+}
+
+// the loop behind `index_error_inputter`: read an index for `xs` from each
+// line of `input`, and write the value there to `output`. Bad input is
+// reported to `output` rather than panicking, and the session ends at the end
+// of the input, or when "q" is entered. Returns how many lines weren't indexes
+pub fn index_session<T: fmt::Display>(
+    xs: &[T],
+    mut input: impl BufRead,
+    mut output: impl Write,
+) -> io::Result<usize> {
+    let mut invalid = 0;
+
+    writeln!(
+        output,
+        "Enter an index for the array of length {}, or q to quit",
+        xs.len()
+    )?;
+
+    loop {
+        write!(output, "> ")?;
+        output.flush()?;
+
+        let mut line = String::new(); // => uninitialised
+
+        if input.read_line(&mut line)? == 0 {
+            writeln!(output)?;
+
+            return Ok(invalid);
+        }
+
+        let line = line.trim();
+        let index: usize = match line {
+            "" => continue,
+            "q" => return Ok(invalid),
+            _ => match line.parse() {
+                Ok(n) => n,
+                Err(e) => {
+                    writeln!(output, "error: {line:?} is not an index: {e}")?;
+                    invalid += 1;
+                    continue;
+                }
+            },
+        };
+
+        // .get rather than xs[index], which panics if out of bounds
+        match xs.get(index) {
+            Some(element) => writeln!(output, "value at index {index} is {element}")?,
+            None => writeln!(
+                output,
+                "error: index {index} is out of bounds for an array of length {}",
+                xs.len()
+            )?,
+        }
+    }
+}
+
+fn index_error_inputter() {
+    let xs = [1, 2, 3, 4, 5];
+
+    let invalid =
+        index_session(&xs, io::stdin().lock(), io::stdout()).expect("failed to read or write");
+
+    // the transcript has each error, and the log gets a diagnostic, if RBE_LOG
+    // lets warnings through
+    if invalid > 0 {
+        warn!("lines that weren't indexes: {invalid}");
+    }
+}
+
+fn size_of_array() {
+    let xs = [0; 500];
+
+    println!("size of xs in bytes: {}", mem::size_of_val(&xs));
+    println!();
+}
+
+fn slices_as_sections_of_array() {
+    let mut temp = 0;
+    let xs: [i32; 500] = [0; 500].map(|_| {
+        temp += 1;
+        temp
+    });
+    let start = 4;
+    let end = 10;
+    let slice = &xs[start..=end]; // => value is borrowed here
+
+    println!("slice from {start} to {end}: {slice:?}");
+    println!();
+}
+
+fn safely_reference_arrays_with_get() {
+    let xs = [1, 2, 3];
+    let index = 4;
+
+    match xs.get(index) {
+        Some(x) => {
+            println!("found {x}");
+        }
+        None => {
+            eprintln!("oops - too far!");
+        }
+    };
+
+    #[allow(clippy::manual_unwrap_or)]
+    let value = match xs.get(index) {
+        Some(x) => x,
+        None => &-1,
+    };
+    println!("value at index {index} is {value}");
+
+    let value = xs.get(index).unwrap_or(&-1);
+    println!("unwrapped: value at index {index} is {value}");
+    println!();
+}
+
+pub const CHAPTER: Chapter = rbe_registry::chapter! {
+    number: 2,
+    name: "primitives",
+    examples: {
+        _: [
+            underscored_integers,
+            isize_usize_for_indexing,
+            addition_and_subtraction,
+            division,
+            big_integers,
+            boolean_logic,
+            bitwise_operations,
+        ],
+        "tuples": [
+            destructuring_tuples,
+            functions_returning_tuples,
+            single_value_tuples_with_comma,
+            tuple_exercise,
+        ],
+        "arrays": [
+            array_definition_without_binding,
+            array_initialisation,
+            size_of_array,
+            slices_as_sections_of_array,
+            safely_reference_arrays_with_get,
+            index_error_inputter,
+        ],
+    },
+};
@@ -0,0 +1,334 @@
+// An R×C matrix, grown from the 2×2 `Matrix` tuple struct in `tuple_exercise`.
+//
+// The dimensions are const generics, so multiplying matrices with mismatched
+// dimensions, or asking for the determinant of a non-square matrix, doesn't
+// compile:
+//
+//     let a: Matrix<f64, 2, 3> = ...;
+//     let b: Matrix<f64, 3, 4> = ...;
+//     let c: Matrix<f64, 2, 4> = a * b;
+//     let d = a * a; // error[E0308]: mismatched types
+use std::fmt;
+use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub};
+
+// the numbers a matrix can hold
+pub trait Element:
+    Copy + PartialEq + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! impl_element {
+    ($($t: ty),+ $(,)?) => {
+        $(
+            impl Element for $t {
+                const ZERO: Self = 0 as $t;
+                const ONE: Self = 1 as $t;
+            }
+        )+
+    };
+}
+
+impl_element!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+// elements that can be divided without truncating, which finding an inverse
+// needs
+pub trait Float: Element + PartialOrd + Div<Output = Self> + Neg<Output = Self> {
+    fn abs(self) -> Self;
+}
+
+impl Float for f32 {
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+}
+
+impl Float for f64 {
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+}
+
+// stored as an array of rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Matrix<T, const R: usize, const C: usize>([[T; C]; R]);
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
+    pub fn new(rows: [[T; C]; R]) -> Self {
+        Matrix(rows)
+    }
+
+    // build a matrix by calling `f` with the row and column of each element
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        Matrix(std::array::from_fn(|row| {
+            std::array::from_fn(|column| f(row, column))
+        }))
+    }
+
+    pub fn rows(&self) -> &[[T; C]; R] {
+        &self.0
+    }
+
+    pub fn into_rows(self) -> [[T; C]; R] {
+        self.0
+    }
+}
+
+impl<T: Copy, const R: usize, const C: usize> Matrix<T, R, C> {
+    pub fn row(&self, row: usize) -> [T; C] {
+        self.0[row]
+    }
+
+    pub fn column(&self, column: usize) -> [T; R] {
+        std::array::from_fn(|row| self.0[row][column])
+    }
+
+    // swap rows and columns
+    pub fn transpose(&self) -> Matrix<T, C, R> {
+        Matrix::from_fn(|row, column| self[(column, row)])
+    }
+
+    // apply `f` to every element
+    pub fn map<U, F>(&self, mut f: F) -> Matrix<U, R, C>
+    where
+        F: FnMut(T) -> U,
+    {
+        Matrix::from_fn(|row, column| f(self[(row, column)]))
+    }
+}
+
+impl<T: Element, const R: usize, const C: usize> Matrix<T, R, C> {
+    pub fn zero() -> Self {
+        Matrix([[T::ZERO; C]; R])
+    }
+}
+
+impl<T: Element, const N: usize> Matrix<T, N, N> {
+    pub fn identity() -> Self {
+        Matrix::from_fn(|row, column| if row == column { T::ONE } else { T::ZERO })
+    }
+
+    // sum of the elements on the main diagonal
+    pub fn trace(&self) -> T {
+        (0..N).fold(T::ZERO, |acc, i| acc + self[(i, i)])
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Element + Div<Output = T> + Neg<Output = T>,
+{
+    // Uses the Bareiss algorithm, which only ever divides by a value that
+    // divides exactly, so integer determinants are exact too
+    pub fn determinant(&self) -> T {
+        let mut m = self.0;
+        let mut sign = T::ONE;
+        let mut previous_pivot = T::ONE;
+
+        for k in 0..N {
+            if m[k][k] == T::ZERO {
+                // swap in a row with a non-zero pivot, flipping the sign of the
+                // determinant. If there isn't one, the matrix is singular
+                match (k + 1..N).find(|&i| m[i][k] != T::ZERO) {
+                    Some(i) => {
+                        m.swap(k, i);
+                        sign = -sign;
+                    }
+                    None => return T::ZERO,
+                }
+            }
+
+            for i in k + 1..N {
+                for j in k + 1..N {
+                    m[i][j] = (m[i][j] * m[k][k] - m[i][k] * m[k][j]) / previous_pivot;
+                }
+            }
+
+            previous_pivot = m[k][k];
+        }
+
+        match N {
+            0 => T::ONE,
+            _ => sign * m[N - 1][N - 1],
+        }
+    }
+}
+
+impl<T: Float, const N: usize> Matrix<T, N, N> {
+    // Gauss-Jordan elimination with partial pivoting. Returns `None` when the
+    // matrix is singular
+    pub fn inverse(&self) -> Option<Self> {
+        let mut m = self.0;
+        let mut inverse = Self::identity().0;
+
+        for k in 0..N {
+            // use the row with the largest value in this column as the pivot
+            // to keep rounding errors small
+            let pivot_row = (k..N).fold(k, |best, i| {
+                if m[i][k].abs() > m[best][k].abs() {
+                    i
+                } else {
+                    best
+                }
+            });
+
+            if m[pivot_row][k] == T::ZERO {
+                return None;
+            }
+
+            m.swap(k, pivot_row);
+            inverse.swap(k, pivot_row);
+
+            let pivot = m[k][k];
+
+            for j in 0..N {
+                m[k][j] = m[k][j] / pivot;
+                inverse[k][j] = inverse[k][j] / pivot;
+            }
+
+            for i in (0..N).filter(|&i| i != k) {
+                let factor = m[i][k];
+
+                for j in 0..N {
+                    m[i][j] = m[i][j] - factor * m[k][j];
+                    inverse[i][j] = inverse[i][j] - factor * inverse[k][j];
+                }
+            }
+        }
+
+        Some(Matrix(inverse))
+    }
+}
+
+impl<T: Element, const R: usize, const C: usize> Default for Matrix<T, R, C> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for Matrix<T, R, C> {
+    fn from(rows: [[T; C]; R]) -> Self {
+        Matrix(rows)
+    }
+}
+
+// matrix[(row, column)]
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<T, R, C> {
+    type Output = T;
+
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        &self.0[row][column]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<T, R, C> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        &mut self.0[row][column]
+    }
+}
+
+impl<T: Element, const R: usize, const C: usize> Add for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Matrix::from_fn(|row, column| self[(row, column)] + rhs[(row, column)])
+    }
+}
+
+impl<T: Element, const R: usize, const C: usize> Sub for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Matrix::from_fn(|row, column| self[(row, column)] - rhs[(row, column)])
+    }
+}
+
+impl<T, const R: usize, const C: usize> Neg for Matrix<T, R, C>
+where
+    T: Element + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(|elem| -elem)
+    }
+}
+
+// an R×C matrix can only be multiplied by a C×K matrix, resulting in an R×K
+// matrix
+impl<T: Element, const R: usize, const C: usize, const K: usize> Mul<Matrix<T, C, K>>
+    for Matrix<T, R, C>
+{
+    type Output = Matrix<T, R, K>;
+
+    fn mul(self, rhs: Matrix<T, C, K>) -> Self::Output {
+        Matrix::from_fn(|row, column| {
+            (0..C).fold(T::ZERO, |acc, k| acc + self[(row, k)] * rhs[(k, column)])
+        })
+    }
+}
+
+// scale every element
+impl<T: Element, const R: usize, const C: usize> Mul<T> for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|elem| elem * rhs)
+    }
+}
+
+// each row in parentheses, with every column right-aligned:
+//
+// ( 1.0  -2.5)
+// (10.0   3.0)
+//
+// formatting options such as precision are applied to each element
+impl<T: fmt::Display, const R: usize, const C: usize> fmt::Display for Matrix<T, R, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cells: Vec<Vec<String>> = self
+            .0
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|elem| match f.precision() {
+                        Some(precision) => format!("{elem:.precision$}"),
+                        None => elem.to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+        let widths: Vec<usize> = (0..C)
+            .map(|column| {
+                cells
+                    .iter()
+                    .map(|row| row[column].chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        for (i, row) in cells.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            write!(f, "(")?;
+
+            for (column, cell) in row.iter().enumerate() {
+                if column > 0 {
+                    write!(f, " ")?;
+                }
+
+                write!(f, "{cell:>width$}", width = widths[column])?;
+            }
+
+            write!(f, ")")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,212 @@
+#[cfg(test)]
+mod bigint_tests {
+    use primitives::bigint::{BigInt, BigUint, ParseBigIntError};
+
+    // deterministic pseudo-random numbers, without pulling in a crate
+    fn xorshift(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    fn big(s: &str) -> BigUint {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn matches_u128_arithmetic() {
+        let mut state = 0x2545_F491_4F6C_DD1D;
+
+        for _ in 0..2000 {
+            // vary the sizes, so every number of 32-bit digits gets divided
+            let a = u128::from(xorshift(&mut state)) << (xorshift(&mut state) % 64);
+            let b = u128::from(xorshift(&mut state)) >> (xorshift(&mut state) % 64);
+            let (x, y) = (BigUint::from(a), BigUint::from(b));
+
+            match a.checked_add(b) {
+                Some(sum) => assert_eq!((&x + &y).to_u128(), Some(sum)),
+                None => assert_eq!((&x + &y).bits(), 129),
+            }
+            assert_eq!(
+                x.checked_sub(&y).map(|n| n.to_u128().unwrap()),
+                a.checked_sub(b)
+            );
+            assert_eq!(x.cmp(&y), a.cmp(&b));
+
+            if let (Some(q), Some(r)) = (a.checked_div(b), a.checked_rem(b)) {
+                assert_eq!((&x / &y).to_u128(), Some(q), "{a} / {b}");
+                assert_eq!((&x % &y).to_u128(), Some(r), "{a} % {b}");
+            }
+
+            let (a, b) = (a as u64, b as u64);
+
+            assert_eq!(
+                (BigUint::from(a) * BigUint::from(b)).to_u128(),
+                Some(u128::from(a) * u128::from(b))
+            );
+        }
+    }
+
+    #[test]
+    fn division_identity_for_large_numbers() {
+        let mut state = 0x9E37_79B9_7F4A_7C15;
+        let random = |state: &mut u64, digits: usize| -> BigUint {
+            (0..digits).fold(BigUint::zero(), |n, _| {
+                n * BigUint::from(u64::MAX) + BigUint::from(xorshift(state))
+            })
+        };
+
+        for i in 1..60 {
+            let a = random(&mut state, 2 + i % 13);
+            let b = random(&mut state, 1 + i % 7) + 1_u32;
+            let (q, r) = a.div_rem(&b);
+
+            assert!(r < b);
+            assert_eq!(&q * &b + &r, a);
+        }
+
+        // a case from "Hacker's Delight" where subtracting the estimated
+        // quotient digit goes negative, and the divisor is added back
+        let a = big("0x7fff_ffff_8000_0000_0000_0000_0000_0000");
+        let b = big("0x8000_0000_0000_0000_0000_0001");
+
+        assert_eq!(
+            a.div_rem(&b),
+            (big("0xffff_fffe"), big("0x7fff_ffff_ffff_ffff_0000_0002"))
+        );
+    }
+
+    #[test]
+    fn signed_arithmetic() {
+        let values = [-7_i64, -2, -1, 0, 1, 3, 7, i64::MIN + 1, i64::MAX];
+
+        for a in values {
+            for b in values {
+                let (x, y) = (BigInt::from(a), BigInt::from(b));
+                let (a, b) = (i128::from(a), i128::from(b));
+
+                assert_eq!((&x + &y).to_i128(), Some(a + b));
+                assert_eq!((&x - &y).to_i128(), Some(a - b));
+                assert_eq!((&x * &y).to_i128(), Some(a * b));
+                assert_eq!(x.cmp(&y), a.cmp(&b));
+
+                // rounding towards zero, like the primitives
+                if let (Some(q), Some(r)) = (a.checked_div(b), a.checked_rem(b)) {
+                    assert_eq!((&x / &y).to_i128(), Some(q), "{a} / {b}");
+                    assert_eq!((&x % &y).to_i128(), Some(r), "{a} % {b}");
+                }
+            }
+        }
+
+        assert_eq!(-BigInt::from(5), BigInt::from(-5));
+        assert_eq!(-BigInt::zero(), BigInt::zero());
+        assert_eq!(BigInt::from(i128::MIN).to_i128(), Some(i128::MIN));
+        assert_eq!((BigInt::from(i128::MIN) - 1_i32).to_i128(), None);
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let max = u128::MAX.to_string();
+
+        assert_eq!(big(&max).to_string(), max);
+        assert_eq!(big("1_000_000").to_string(), "1000000");
+        assert_eq!(big("0xff_ff").to_string(), "65535");
+        assert_eq!(big("0b1010").to_string(), "10");
+        assert_eq!(big("+0o777").to_string(), "511");
+        assert_eq!(
+            BigUint::from_str_radix("zz", 36).unwrap(),
+            BigUint::from(35 * 36 + 35_u32)
+        );
+
+        let n = BigUint::from(2_u32).pow(100);
+
+        for radix in 2..=36 {
+            let digits = n.to_str_radix(radix);
+
+            assert_eq!(BigUint::from_str_radix(&digits, radix), Ok(n.clone()));
+        }
+
+        let x: BigInt = "-12_345".parse().unwrap();
+
+        assert_eq!(x, BigInt::from(-12345));
+        assert_eq!(format!("{x}|{x:>8}|{x:08}"), "-12345|  -12345|-0012345");
+        assert_eq!(format!("{:+}", BigInt::from(5)), "+5");
+        assert_eq!(
+            format!(
+                "{:x} {:#X} {:o} {:#b}",
+                x,
+                x,
+                BigUint::from(8_u8),
+                BigInt::from(5)
+            ),
+            "-3039 -0x3039 10 0b101"
+        );
+        assert_eq!(format!("{:?}", vec![BigUint::from(1_u8)]), "[1]");
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!("".parse::<BigUint>(), Err(ParseBigIntError::Empty));
+        assert_eq!("0x".parse::<BigUint>(), Err(ParseBigIntError::Empty));
+        assert_eq!("-".parse::<BigInt>(), Err(ParseBigIntError::Empty));
+        assert_eq!(
+            "_1".parse::<BigUint>(),
+            Err(ParseBigIntError::InvalidDigit('_'))
+        );
+        assert_eq!(
+            "-5".parse::<BigUint>(),
+            Err(ParseBigIntError::InvalidDigit('-'))
+        );
+        assert_eq!(
+            "--5".parse::<BigInt>(),
+            Err(ParseBigIntError::InvalidDigit('-'))
+        );
+        assert_eq!(
+            BigUint::from_str_radix("12", 2),
+            Err(ParseBigIntError::InvalidDigit('2'))
+        );
+        assert_eq!(
+            "1 2".parse::<BigInt>().unwrap_err().to_string(),
+            "invalid digit ' ' found in string"
+        );
+    }
+
+    #[test]
+    fn pow_and_gcd() {
+        assert_eq!(
+            BigUint::from(2_u8).pow(128),
+            BigUint::from(u128::MAX) + 1_u8
+        );
+        assert_eq!(BigUint::from(7_u8).pow(0), BigUint::one());
+        assert_eq!(BigInt::from(-3).pow(3), BigInt::from(-27));
+        assert_eq!(BigInt::from(-3).pow(4), BigInt::from(81));
+
+        let a = BigUint::from(2_u8).pow(80) * 3_u8;
+        let b = BigUint::from(2_u8).pow(70) * 9_u8;
+
+        assert_eq!(a.gcd(&b), BigUint::from(2_u8).pow(70) * 3_u8);
+        assert_eq!(BigInt::from(-12).gcd(&BigInt::from(18)), BigInt::from(6));
+        assert_eq!(
+            BigUint::zero().gcd(&BigUint::from(5_u8)),
+            BigUint::from(5_u8)
+        );
+
+        let factorial: BigUint = (1..=25_u32).map(BigUint::from).product();
+
+        assert_eq!(factorial.to_string(), "15511210043330985984000000");
+        assert_eq!(factorial.bits(), 84);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn unsigned_subtraction_overflows() {
+        let _ = BigUint::from(1_u8) - BigUint::from(2_u8);
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must lie in the range `[2, 36]` - found 1")]
+    fn signed_parsing_checks_the_radix() {
+        let _ = BigInt::from_str_radix("-101", 1);
+    }
+}
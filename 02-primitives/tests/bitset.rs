@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod bitset_tests {
+    use primitives::bitset::BitSet;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn insert_remove_contains() {
+        let mut set = BitSet::new();
+
+        assert!(set.is_empty());
+        assert!(set.insert(3));
+        assert!(!set.insert(3));
+        assert!(set.insert(200));
+        assert!(set.contains(&3));
+        assert!(set.contains(&200));
+        assert!(!set.contains(&4));
+        assert!(!set.contains(&100_000));
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.capacity(), 201);
+
+        assert!(set.remove(3));
+        assert!(!set.remove(3));
+        assert!(!set.remove(100_000));
+        assert_eq!(set.count_ones(), 1);
+
+        set.clear();
+
+        assert!(set.is_empty());
+        assert_eq!(set.capacity(), 201);
+    }
+
+    #[test]
+    fn iterates_in_order() {
+        let values = [0, 1, 63, 64, 65, 127, 128, 1000, 4095];
+        let set = BitSet::from_iter(values.iter().rev().copied());
+
+        assert_eq!(set.iter().collect::<Vec<_>>(), values);
+        assert_eq!(format!("{set:?}"), format!("{:?}", BTreeSet::from(values)));
+        assert_eq!(BitSet::new().iter().next(), None);
+    }
+
+    #[test]
+    fn operators_match_hash_set() {
+        let mut state = 0x853C_49E6_748F_EA9B_u64;
+        let mut random_set = || -> BTreeSet<u32> {
+            (0..50)
+                .map(|_| {
+                    state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                    (state >> 33) as u32 % 300
+                })
+                .collect()
+        };
+
+        for _ in 0..20 {
+            let (a, b) = (random_set(), random_set());
+            let (x, y) = (
+                BitSet::from_iter(a.iter().copied()),
+                BitSet::from_iter(b.iter().copied()),
+            );
+            let to_vec = |set: &BTreeSet<u32>| set.iter().copied().collect::<Vec<_>>();
+
+            assert_eq!((&x | &y).iter().collect::<Vec<_>>(), to_vec(&(&a | &b)));
+            assert_eq!((&x & &y).iter().collect::<Vec<_>>(), to_vec(&(&a & &b)));
+            assert_eq!((&x ^ &y).iter().collect::<Vec<_>>(), to_vec(&(&a ^ &b)));
+            assert_eq!((&x - &y).iter().collect::<Vec<_>>(), to_vec(&(&a - &b)));
+
+            assert_eq!(x.union(&y).collect::<Vec<_>>(), to_vec(&(&a | &b)));
+            assert_eq!(x.intersection(&y).collect::<Vec<_>>(), to_vec(&(&a & &b)));
+            assert_eq!(x.is_subset(&y), a.is_subset(&b));
+            assert_eq!(x.is_disjoint(&y), a.is_disjoint(&b));
+        }
+    }
+
+    #[test]
+    fn not_stays_within_capacity() {
+        let mut set = BitSet::with_capacity(6);
+
+        set.extend([0, 2, 5]);
+
+        assert_eq!(!&set, BitSet::from([1, 3, 4]));
+        assert_eq!((!&set).capacity(), 6);
+        assert_eq!(!!set.clone(), set);
+
+        let full = !BitSet::with_capacity(130);
+
+        assert_eq!(full.count_ones(), 130);
+        assert_eq!(full.iter().last(), Some(129));
+    }
+
+    #[test]
+    fn equality_ignores_capacity() {
+        let mut a = BitSet::with_capacity(1000);
+
+        a.insert(7);
+
+        assert_eq!(a, BitSet::from([7]));
+        assert_ne!(a, BitSet::from([7, 8]));
+        assert!(a.is_superset(&BitSet::with_capacity(10)));
+    }
+
+    #[test]
+    fn binary_format() {
+        let mut set = BitSet::with_capacity(4);
+
+        set.extend([0, 2]);
+
+        assert_eq!(format!("{set:b}"), "0101");
+        assert_eq!(format!("{set:#b}"), "0b0101");
+        assert_eq!(format!("{set:>8b}"), "    0101");
+        assert_eq!(format!("{:b}", BitSet::from([1, 2, 3])), "1110");
+        assert_eq!(format!("{:b}", BitSet::new()), "0");
+        assert_eq!(format!("{:?}", set.union(&BitSet::from([1]))), "[0, 1, 2]");
+    }
+}
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod index_session_tests {
+    use primitives::index_session;
+
+    const HEADER: &str = "Enter an index for the array of length 5, or q to quit\n";
+
+    // run a session over [1, 2, 3, 4, 5] with `input`, returning everything it
+    // printed after the header, and how many lines weren't indexes
+    fn session(input: &str) -> (String, usize) {
+        let mut output = vec![];
+        let invalid = index_session(&[1, 2, 3, 4, 5], input.as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        (output.strip_prefix(HEADER).unwrap().to_string(), invalid)
+    }
+
+    fn transcript(input: &str) -> String {
+        session(input).0
+    }
+
+    #[test]
+    fn looks_up_each_index() {
+        assert_eq!(
+            transcript("0\n4\n"),
+            "> value at index 0 is 1\n> value at index 4 is 5\n> \n"
+        );
+    }
+
+    #[test]
+    fn q_quits() {
+        assert_eq!(transcript("2\nq\n3\n"), "> value at index 2 is 3\n> ");
+        assert_eq!(transcript("  q  \n"), "> ");
+    }
+
+    #[test]
+    fn ends_at_end_of_input() {
+        assert_eq!(transcript(""), "> \n");
+        // a last line without a newline is still read
+        assert_eq!(transcript("1"), "> value at index 1 is 2\n> \n");
+    }
+
+    #[test]
+    fn out_of_bounds_indexes_are_reported() {
+        assert_eq!(
+            transcript("5\n1\n"),
+            "> error: index 5 is out of bounds for an array of length 5\n\
+             > value at index 1 is 2\n> \n"
+        );
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        assert_eq!(
+            session("-1\nquit\n\n3\n"),
+            (
+                "> error: \"-1\" is not an index: invalid digit found in string\n\
+                 > error: \"quit\" is not an index: invalid digit found in string\n\
+                 > > value at index 3 is 4\n> \n"
+                    .to_string(),
+                2
+            )
+        );
+        // out of bounds indexes are still indexes
+        assert_eq!(session("5\n1\n").1, 0);
+    }
+
+    #[test]
+    fn works_with_any_display_values() {
+        let mut output = vec![];
+
+        index_session(&["a", "b"], "1\n".as_bytes(), &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Enter an index for the array of length 2, or q to quit\n> value at index 1 is b\n> \n"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod matrix_tests {
+    use primitives::matrix::Matrix;
+
+    fn assert_close<const N: usize>(a: Matrix<f64, N, N>, b: Matrix<f64, N, N>) {
+        for row in 0..N {
+            for column in 0..N {
+                let (x, y) = (a[(row, column)], b[(row, column)]);
+
+                assert!((x - y).abs() < 1e-9, "{a}\n!=\n{b}");
+            }
+        }
+    }
+
+    #[test]
+    fn transpose() {
+        let m = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+        let t: Matrix<i32, 3, 2> = m.transpose();
+
+        assert_eq!(t, Matrix::new([[1, 4], [2, 5], [3, 6]]));
+        assert_eq!(t.transpose(), m);
+        assert_eq!(m.row(1), [4, 5, 6]);
+        assert_eq!(m.column(2), [3, 6]);
+    }
+
+    #[test]
+    fn add_sub_and_scale() {
+        let a = Matrix::new([[1, 2], [3, 4]]);
+        let b = Matrix::new([[10, 20], [30, 40]]);
+
+        assert_eq!(a + b, Matrix::new([[11, 22], [33, 44]]));
+        assert_eq!(b - a, Matrix::new([[9, 18], [27, 36]]));
+        assert_eq!(a * 10, b);
+        assert_eq!(-a, Matrix::new([[-1, -2], [-3, -4]]));
+        assert_eq!(a - a, Matrix::zero());
+    }
+
+    #[test]
+    fn multiply() {
+        let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+        let b = Matrix::new([[7, 8], [9, 10], [11, 12]]);
+        let c: Matrix<i32, 2, 2> = a * b;
+
+        assert_eq!(c, Matrix::new([[58, 64], [139, 154]]));
+        assert_eq!(a * Matrix::<i32, 3, 3>::identity(), a);
+
+        let column = Matrix::new([[1], [1], [1]]);
+
+        assert_eq!(a * column, Matrix::new([[6], [15]]));
+    }
+
+    #[test]
+    fn indexing() {
+        let mut m: Matrix<u8, 2, 3> = Matrix::default();
+
+        m[(1, 2)] = 7;
+
+        assert_eq!(m[(1, 2)], 7);
+        assert_eq!(m.into_rows(), [[0, 0, 0], [0, 0, 7]]);
+
+        let m: Matrix<usize, 3, 2> = Matrix::from_fn(|row, column| row * 10 + column);
+
+        assert_eq!(m[(2, 1)], 21);
+    }
+
+    #[test]
+    fn determinant() {
+        assert_eq!(Matrix::new([[3, 8], [4, 6]]).determinant(), -14);
+        assert_eq!(
+            Matrix::new([[6, 1, 1], [4, -2, 5], [2, 8, 7]]).determinant(),
+            -306
+        );
+        // needs a row swap to find a non-zero pivot
+        assert_eq!(
+            Matrix::new([[0, 2, 1], [1, 0, 0], [0, 1, 3]]).determinant(),
+            -5
+        );
+        assert_eq!(Matrix::new([[1, 2], [2, 4]]).determinant(), 0);
+        assert_eq!(Matrix::<i64, 4, 4>::identity().determinant(), 1);
+        assert_eq!(Matrix::<i64, 0, 0>::new([]).determinant(), 1);
+        assert!((Matrix::new([[4.0, 7.0], [2.0, 6.0]]).determinant() - 10.0_f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn inverse() {
+        let m = Matrix::new([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]);
+        let inverse = m.inverse().unwrap();
+
+        assert_close(m * inverse, Matrix::identity());
+        assert_close(inverse * m, Matrix::identity());
+        assert_close(inverse.inverse().unwrap(), m);
+
+        assert_eq!(Matrix::new([[1.0, 2.0], [2.0, 4.0]]).inverse(), None);
+        assert_eq!(Matrix::<f32, 2, 2>::identity().trace(), 2.0);
+    }
+
+    #[test]
+    fn display_aligns_columns() {
+        let m = Matrix::new([[1.0, -2.5], [10.0, 3.0]]);
+
+        assert_eq!(m.to_string(), "( 1 -2.5)\n(10    3)");
+        assert_eq!(format!("{m:.1}"), "( 1.0 -2.5)\n(10.0  3.0)");
+        assert_eq!(Matrix::new([[1, 2, 3]]).to_string(), "(1 2 3)");
+    }
+}
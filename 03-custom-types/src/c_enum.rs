@@ -0,0 +1,156 @@
+// Conversions for C-like enums, grown from `enum_discriminators`, which could
+// cast a variant to its discriminant with `as` but had no way back.
+//
+// `c_enum!` declares a fieldless enum, as the enum itself would be written,
+// and adds conversions both ways:
+//
+//     c_enum! {
+//         pub enum Color {
+//             Red = 1,
+//             Green,
+//             Blue = 10,
+//         }
+//     }
+//
+//     Color::try_from(2_u8);       // Ok(Color::Green)
+//     Color::try_from(3_i32);      // Err(3 is not a discriminant of Color)
+//     "Blue".parse::<Color>();     // Ok(Color::Blue)
+//     Color::Red.to_string();      // "Red"
+//     Color::ALL;                  // [Red, Green, Blue]
+//     Color::iter().count();       // 3
+//
+// Numbers from outside the program, e.g. a byte read from a file, can then be
+// checked with `try_from`, rather than transmuted or matched by hand.
+//
+// The enum derives `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq` and `Hash`, so
+// those can't be derived again, but other attributes, e.g. `#[repr(u8)]` or
+// `#[derive(PartialOrd)]`, are passed on to it.
+use std::error;
+use std::fmt;
+
+// a number that isn't the discriminant of any variant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDiscriminant {
+    pub enum_name: &'static str,
+    pub value: i64,
+}
+
+impl fmt::Display for InvalidDiscriminant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} is not a discriminant of {}",
+            self.value, self.enum_name
+        )
+    }
+}
+
+impl error::Error for InvalidDiscriminant {}
+
+// a string that isn't the name of any variant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseVariantError {
+    pub enum_name: &'static str,
+    pub name: String,
+}
+
+impl fmt::Display for ParseVariantError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not a variant of {}", self.name, self.enum_name)
+    }
+}
+
+impl error::Error for ParseVariantError {}
+
+#[macro_export]
+macro_rules! c_enum {
+    // counts the variants, for the length of `ALL`
+    (@one $variant: ident) => {
+        1
+    };
+
+    (
+        $(#[$meta: meta])*
+        $visibility: vis enum $name: ident {
+            $(
+                $(#[$variant_meta: meta])*
+                $variant: ident $(= $discriminant: expr)?
+            ),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $visibility enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant $(= $discriminant)?,
+            )+
+        }
+
+        impl $name {
+            // every variant, in the order they're declared
+            pub const ALL: [$name; 0 $(+ $crate::c_enum!(@one $variant))+] =
+                [$($name::$variant),+];
+
+            pub fn iter() -> impl Iterator<Item = $name> {
+                $name::ALL.into_iter()
+            }
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $($name::$variant => stringify!($variant),)+
+                }
+            }
+
+            // the variant whose discriminant is `value`. Every discriminant
+            // is compared as an i128, which holds every value of every repr
+            // up to u64, so e.g. a u8 can't wrap around to match a larger
+            // discriminant, and -1 can't match u64::MAX
+            fn from_discriminant(
+                value: i64,
+            ) -> ::std::result::Result<$name, $crate::c_enum::InvalidDiscriminant> {
+                $name::iter()
+                    .find(|variant| *variant as i128 == i128::from(value))
+                    .ok_or($crate::c_enum::InvalidDiscriminant {
+                        enum_name: stringify!($name),
+                        value,
+                    })
+            }
+        }
+
+        impl ::std::convert::TryFrom<i32> for $name {
+            type Error = $crate::c_enum::InvalidDiscriminant;
+
+            fn try_from(value: i32) -> ::std::result::Result<$name, Self::Error> {
+                $name::from_discriminant(i64::from(value))
+            }
+        }
+
+        impl ::std::convert::TryFrom<u8> for $name {
+            type Error = $crate::c_enum::InvalidDiscriminant;
+
+            fn try_from(value: u8) -> ::std::result::Result<$name, Self::Error> {
+                $name::from_discriminant(i64::from(value))
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = $crate::c_enum::ParseVariantError;
+
+            fn from_str(s: &str) -> ::std::result::Result<$name, Self::Err> {
+                $name::iter()
+                    .find(|variant| variant.name() == s)
+                    .ok_or_else(|| $crate::c_enum::ParseVariantError {
+                        enum_name: stringify!($name),
+                        name: s.to_string(),
+                    })
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.pad(self.name())
+            }
+        }
+    };
+}
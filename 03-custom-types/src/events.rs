@@ -0,0 +1,355 @@
+// An event bus, grown from `enum_matching`'s `Event` and its `handle_event`
+// match. Instead of one function matching every variant, handlers register
+// for the kinds of events they care about, and can be removed again:
+//
+//     let mut bus = EventBus::new();
+//
+//     let id = bus.on(EventKind::KeyPress, |event, emitter| {
+//         if let Event::KeyPress('j') = event {
+//             emitter.emit(Event::Scroll(ScrollDir::Down));
+//         }
+//     });
+//
+//     bus.push(Event::KeyPress('j'));
+//     bus.run(); // handles the key press, then the scroll it emitted
+//     bus.off(id);
+//
+// Events are handled in the order they're pushed, each by its handlers in the
+// order they were registered. Events emitted by handlers go to the back of
+// the queue, so they're handled after the ones that were already waiting.
+//
+// A bus can also be driven by a script, one event per line, so a flow of
+// clicks and key presses can be replayed without a terminal:
+//
+//     # comments and blank lines are skipped
+//     click 32 4
+//     key m
+//     scroll right
+//
+// Keys that are whitespace or control characters, which couldn't be told
+// apart from the spaces between words, are written as escapes, e.g. a space
+// is `key \u{20}`.
+use std::collections::VecDeque;
+use std::error;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScrollDir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Event {
+    MouseClick(i32, i32),
+    KeyPress(char),
+    Scroll(ScrollDir),
+}
+
+// an `Event` without its data, to register handlers for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventKind {
+    MouseClick,
+    KeyPress,
+    Scroll,
+}
+
+impl Event {
+    pub fn kind(&self) -> EventKind {
+        match self {
+            Event::MouseClick(..) => EventKind::MouseClick,
+            Event::KeyPress(_) => EventKind::KeyPress,
+            Event::Scroll(_) => EventKind::Scroll,
+        }
+    }
+}
+
+impl fmt::Display for ScrollDir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ScrollDir::Up => "up",
+            ScrollDir::Down => "down",
+            ScrollDir::Left => "left",
+            ScrollDir::Right => "right",
+        };
+
+        f.pad(name)
+    }
+}
+
+// the same format scripts use, so an event always parses back from its
+// Display
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Event::MouseClick(x, y) => write!(f, "click {x} {y}"),
+            Event::KeyPress(key) if key.is_whitespace() || key.is_control() => {
+                write!(f, "key {}", key.escape_unicode())
+            }
+            Event::KeyPress(key) => write!(f, "key {key}"),
+            Event::Scroll(dir) => write!(f, "scroll {dir}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseEventError {
+    Empty,
+    UnknownEvent(String),
+    InvalidArguments(String),
+}
+
+impl fmt::Display for ParseEventError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseEventError::Empty => write!(f, "cannot parse event from empty string"),
+            ParseEventError::UnknownEvent(name) => {
+                write!(f, "unknown event {name:?}, expected click, key or scroll")
+            }
+            ParseEventError::InvalidArguments(event) => write!(
+                f,
+                "invalid event {event:?}, expected e.g. click 3 4, key m or scroll up"
+            ),
+        }
+    }
+}
+
+impl error::Error for ParseEventError {}
+
+impl FromStr for ScrollDir {
+    type Err = ParseEventError;
+
+    fn from_str(s: &str) -> Result<ScrollDir, Self::Err> {
+        match s {
+            "up" => Ok(ScrollDir::Up),
+            "down" => Ok(ScrollDir::Down),
+            "left" => Ok(ScrollDir::Left),
+            "right" => Ok(ScrollDir::Right),
+            _ => Err(ParseEventError::InvalidArguments(s.to_string())),
+        }
+    }
+}
+
+impl FromStr for Event {
+    type Err = ParseEventError;
+
+    fn from_str(s: &str) -> Result<Event, Self::Err> {
+        let words: Vec<&str> = s.split_whitespace().collect();
+        let invalid = || ParseEventError::InvalidArguments(s.trim().to_string());
+        let coordinate = |word: &str| word.parse::<i32>().map_err(|_| invalid());
+
+        match words.as_slice() {
+            [] => Err(ParseEventError::Empty),
+            ["click", x, y] => Ok(Event::MouseClick(coordinate(x)?, coordinate(y)?)),
+            ["key", key] => {
+                let mut chars = key.chars();
+
+                match (chars.next(), chars.next()) {
+                    (Some(key), None) => Ok(Event::KeyPress(key)),
+                    // an escaped key, e.g. \u{20} for a space
+                    _ => key
+                        .strip_prefix("\\u{")
+                        .and_then(|key| key.strip_suffix('}'))
+                        .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                        .and_then(char::from_u32)
+                        .map(Event::KeyPress)
+                        .ok_or_else(invalid),
+                }
+            }
+            ["scroll", dir] => Ok(Event::Scroll(dir.parse().map_err(|_| invalid())?)),
+            ["click" | "key" | "scroll", ..] => Err(invalid()),
+            [name, ..] => Err(ParseEventError::UnknownEvent(name.to_string())),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ScriptError {
+    Io(io::Error),
+    // the line number counts from 1. The lines before it have already run,
+    // and `handled` events with them
+    Parse {
+        line: usize,
+        error: ParseEventError,
+        handled: usize,
+    },
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScriptError::Io(error) => write!(f, "could not read script: {error}"),
+            ScriptError::Parse {
+                line,
+                error,
+                handled,
+            } => write!(f, "line {line}: {error}, after handling {handled} events"),
+        }
+    }
+}
+
+impl error::Error for ScriptError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ScriptError::Io(error) => Some(error),
+            ScriptError::Parse { error, .. } => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for ScriptError {
+    fn from(error: io::Error) -> ScriptError {
+        ScriptError::Io(error)
+    }
+}
+
+// returned when registering a handler, to unregister it with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HandlerId(u64);
+
+// given to handlers, to queue follow-up events
+pub struct Emitter<'a> {
+    queue: &'a mut VecDeque<Event>,
+}
+
+impl Emitter<'_> {
+    pub fn emit(&mut self, event: Event) {
+        self.queue.push_back(event);
+    }
+}
+
+type Handler = Box<dyn FnMut(&Event, &mut Emitter<'_>)>;
+
+struct Registration {
+    id: HandlerId,
+    // None for handlers of every kind of event
+    kind: Option<EventKind>,
+    handler: Handler,
+}
+
+#[derive(Default)]
+pub struct EventBus {
+    handlers: Vec<Registration>,
+    queue: VecDeque<Event>,
+    next_id: u64,
+}
+
+impl EventBus {
+    pub fn new() -> EventBus {
+        EventBus::default()
+    }
+
+    fn register(&mut self, kind: Option<EventKind>, handler: Handler) -> HandlerId {
+        let id = HandlerId(self.next_id);
+
+        self.next_id += 1;
+        self.handlers.push(Registration { id, kind, handler });
+
+        id
+    }
+
+    // handle every event of one kind
+    pub fn on<F>(&mut self, kind: EventKind, handler: F) -> HandlerId
+    where
+        F: FnMut(&Event, &mut Emitter<'_>) + 'static,
+    {
+        self.register(Some(kind), Box::new(handler))
+    }
+
+    // handle every event
+    pub fn on_any<F>(&mut self, handler: F) -> HandlerId
+    where
+        F: FnMut(&Event, &mut Emitter<'_>) + 'static,
+    {
+        self.register(None, Box::new(handler))
+    }
+
+    // true if the handler was registered
+    pub fn off(&mut self, id: HandlerId) -> bool {
+        let before = self.handlers.len();
+
+        self.handlers.retain(|registration| registration.id != id);
+
+        self.handlers.len() < before
+    }
+
+    pub fn push(&mut self, event: Event) {
+        self.queue.push_back(event);
+    }
+
+    // the events waiting to be handled, first to last
+    pub fn pending(&self) -> impl Iterator<Item = &Event> {
+        self.queue.iter()
+    }
+
+    // handle the event at the front of the queue, returning it, or None if
+    // the queue is empty
+    pub fn dispatch(&mut self) -> Option<Event> {
+        let event = self.queue.pop_front()?;
+        let mut emitter = Emitter {
+            queue: &mut self.queue,
+        };
+
+        for registration in &mut self.handlers {
+            if registration.kind.is_none_or(|kind| kind == event.kind()) {
+                (registration.handler)(&event, &mut emitter);
+            }
+        }
+
+        Some(event)
+    }
+
+    // handle events until the queue is empty, including the ones emitted
+    // along the way, returning how many were handled. Handlers that always
+    // emit another event will keep this running forever
+    pub fn run(&mut self) -> usize {
+        let mut handled = 0;
+
+        while self.dispatch().is_some() {
+            handled += 1;
+        }
+
+        handled
+    }
+
+    // push each event in a script, and run the bus after each one, so that
+    // follow-up events are handled before the next line, as they would be if
+    // someone were typing. A line that doesn't parse stops the script, and
+    // the error says how many events the lines before it handled
+    pub fn run_script(&mut self, script: impl BufRead) -> Result<usize, ScriptError> {
+        let mut handled = 0;
+
+        for (i, line) in script.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let event = line.parse().map_err(|error| ScriptError::Parse {
+                line: i + 1,
+                error,
+                handled,
+            })?;
+
+            self.push(event);
+            handled += self.run();
+        }
+
+        Ok(handled)
+    }
+}
+
+impl fmt::Debug for EventBus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventBus")
+            .field("handlers", &self.handlers.len())
+            .field("queue", &self.queue)
+            .finish()
+    }
+}
@@ -0,0 +1,604 @@
+// 2D shapes, grown from the `Point` and `Rectangle` in `struct_exercise`, which
+// had cousins in chapters 9, 14 and 19 - each with its own fields and methods.
+//
+// Every shape is generic over its coordinates, and defaults to `f64`, as
+// `Point<T = f32>` did in the exercise:
+//
+//     let rect = Rect::new(Point::new(3.0, 5.2), Point::new(1.0, 2.3));
+//     let circle = Circle::new(Point::new(0, 0), 5);
+//
+//     rect.area();           // 5.8, whichever corners come first
+//     circle.bounding_box(); // Rect (-5, -5) to (5, 5)
+//
+// A `Rect` is always normalised: `min` has the smallest x and y, and `max`
+// the largest, so its width and height are never negative. Areas and lengths
+// are `f64`, as a circle's area isn't a whole number even when its radius is,
+// and they're worked out in `f64` too, so a shape whose coordinates fit in an
+// `i8` can still measure more than 127 across.
+pub mod canvas;
+
+use canvas::Canvas;
+use formatted_print::pretty::{Doc, ToDoc};
+use std::f64::consts::PI;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+
+// the numbers coordinates can be
+pub trait Number:
+    Copy
+    + PartialOrd
+    + fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn to_f64(self) -> f64;
+
+    fn abs(self) -> Self {
+        if self < Self::ZERO {
+            -self
+        } else {
+            self
+        }
+    }
+}
+
+macro_rules! impl_number {
+    ($($t: ty),+ $(,)?) => {
+        $(
+            impl Number for $t {
+                const ZERO: Self = 0 as $t;
+                const ONE: Self = 1 as $t;
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )+
+    };
+}
+
+impl_number!(i8, i16, i32, i64, isize, f32, f64);
+
+// PartialOrd only has min and max for Ord types
+fn min<T: Number>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn max<T: Number>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+// what every shape can measure
+pub trait HasArea {
+    type Unit: Number;
+
+    fn area(&self) -> f64;
+
+    // the distance around the edge
+    fn perimeter(&self) -> f64;
+
+    // the smallest rectangle containing the whole shape
+    fn bounding_box(&self) -> Rect<Self::Unit>;
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Point<T = f64> {
+    pub x: T,
+    pub y: T,
+}
+
+// the difference between two points
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Vector<T = f64> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct Rect<T = f64> {
+    min: Point<T>,
+    max: Point<T>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct Circle<T = f64> {
+    center: Point<T>,
+    radius: T,
+}
+
+// the vertices in order around the edge, which is closed back to the first
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon<T = f64> {
+    vertices: Vec<Point<T>>,
+}
+
+impl<T: Number> Point<T> {
+    pub fn new(x: T, y: T) -> Point<T> {
+        Point { x, y }
+    }
+
+    pub fn origin() -> Point<T> {
+        Point::new(T::ZERO, T::ZERO)
+    }
+
+    pub fn distance_to(&self, other: &Point<T>) -> f64 {
+        (other.to_f64() - self.to_f64()).length()
+    }
+
+    fn to_f64(self) -> Point<f64> {
+        Point::new(self.x.to_f64(), self.y.to_f64())
+    }
+
+    pub fn translate(&mut self, by: Vector<T>) {
+        *self = Point::new(self.x + by.x, self.y + by.y);
+    }
+}
+
+impl<T: Number> Vector<T> {
+    pub fn new(x: T, y: T) -> Vector<T> {
+        Vector { x, y }
+    }
+
+    pub fn dot(&self, other: &Vector<T>) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    // the z part of the 3D cross product: positive if `other` is
+    // anticlockwise from self, negative if it's clockwise, and 0 if they're
+    // parallel
+    pub fn cross(&self, other: &Vector<T>) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn length(&self) -> f64 {
+        self.x.to_f64().hypot(self.y.to_f64())
+    }
+}
+
+impl<T: Number> Add<Vector<T>> for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, v: Vector<T>) -> Point<T> {
+        Point::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl<T: Number> Sub<Vector<T>> for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, v: Vector<T>) -> Point<T> {
+        Point::new(self.x - v.x, self.y - v.y)
+    }
+}
+
+// the vector from `other` to self
+impl<T: Number> Sub for Point<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, other: Point<T>) -> Vector<T> {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Number> Add for Vector<T> {
+    type Output = Vector<T>;
+
+    fn add(self, other: Vector<T>) -> Vector<T> {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Number> Sub for Vector<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, other: Vector<T>) -> Vector<T> {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Number> Neg for Vector<T> {
+    type Output = Vector<T>;
+
+    fn neg(self) -> Vector<T> {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Number> Mul<T> for Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, scalar: T) -> Vector<T> {
+        Vector::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl<T: Number> AddAssign<Vector<T>> for Point<T> {
+    fn add_assign(&mut self, v: Vector<T>) {
+        self.translate(v);
+    }
+}
+
+impl<T: Number> Rect<T> {
+    // any two opposite corners, in either order
+    pub fn new(a: Point<T>, b: Point<T>) -> Rect<T> {
+        Rect {
+            min: Point::new(min(a.x, b.x), min(a.y, b.y)),
+            max: Point::new(max(a.x, b.x), max(a.y, b.y)),
+        }
+    }
+
+    // a corner, and how far the opposite corner is from it
+    pub fn with_size(corner: Point<T>, width: T, height: T) -> Rect<T> {
+        Rect::new(corner, corner + Vector::new(width, height))
+    }
+
+    pub fn square(corner: Point<T>, size: T) -> Rect<T> {
+        Rect::with_size(corner, size, size)
+    }
+
+    // the corner with the smallest x and y
+    pub fn min(&self) -> Point<T> {
+        self.min
+    }
+
+    // the corner with the largest x and y
+    pub fn max(&self) -> Point<T> {
+        self.max
+    }
+
+    // the width and height have to fit in `T`, which area and perimeter don't
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y
+    }
+
+    // rounded towards the min corner for integers
+    pub fn center(&self) -> Point<T> {
+        let two = T::ONE + T::ONE;
+
+        Point::new(
+            self.min.x + self.width() / two,
+            self.min.y + self.height() / two,
+        )
+    }
+
+    // points on the edge count as inside
+    pub fn contains(&self, point: &Point<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    pub fn contains_rect(&self, other: &Rect<T>) -> bool {
+        self.contains(&other.min) && self.contains(&other.max)
+    }
+
+    // rectangles that only share an edge or corner intersect
+    pub fn intersects(&self, other: &Rect<T>) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    // the area both rectangles cover, if any
+    pub fn intersection(&self, other: &Rect<T>) -> Option<Rect<T>> {
+        let low = Point::new(max(self.min.x, other.min.x), max(self.min.y, other.min.y));
+        let high = Point::new(min(self.max.x, other.max.x), min(self.max.y, other.max.y));
+
+        (low.x <= high.x && low.y <= high.y).then_some(Rect {
+            min: low,
+            max: high,
+        })
+    }
+
+    // the smallest rectangle covering both
+    pub fn union(&self, other: &Rect<T>) -> Rect<T> {
+        Rect {
+            min: Point::new(min(self.min.x, other.min.x), min(self.min.y, other.min.y)),
+            max: Point::new(max(self.max.x, other.max.x), max(self.max.y, other.max.y)),
+        }
+    }
+
+    pub fn translate(&mut self, by: Vector<T>) {
+        self.min.translate(by);
+        self.max.translate(by);
+    }
+}
+
+impl<T: Number> HasArea for Rect<T> {
+    type Unit = T;
+
+    fn area(&self) -> f64 {
+        let size = self.max.to_f64() - self.min.to_f64();
+
+        size.x * size.y
+    }
+
+    fn perimeter(&self) -> f64 {
+        let size = self.max.to_f64() - self.min.to_f64();
+
+        2.0 * (size.x + size.y)
+    }
+
+    fn bounding_box(&self) -> Rect<T> {
+        *self
+    }
+}
+
+impl<T: Number> Circle<T> {
+    // a negative radius is the same as a positive one
+    pub fn new(center: Point<T>, radius: T) -> Circle<T> {
+        Circle {
+            center,
+            radius: radius.abs(),
+        }
+    }
+
+    pub fn center(&self) -> Point<T> {
+        self.center
+    }
+
+    pub fn radius(&self) -> T {
+        self.radius
+    }
+
+    pub fn contains(&self, point: &Point<T>) -> bool {
+        let d = point.to_f64() - self.center.to_f64();
+
+        d.dot(&d) <= self.radius.to_f64().powi(2)
+    }
+
+    pub fn intersects(&self, other: &Circle<T>) -> bool {
+        let d = other.center.to_f64() - self.center.to_f64();
+        let radii = self.radius.to_f64() + other.radius.to_f64();
+
+        d.dot(&d) <= radii * radii
+    }
+
+    pub fn translate(&mut self, by: Vector<T>) {
+        self.center.translate(by);
+    }
+}
+
+impl<T: Number> HasArea for Circle<T> {
+    type Unit = T;
+
+    fn area(&self) -> f64 {
+        PI * self.radius.to_f64().powi(2)
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * PI * self.radius.to_f64()
+    }
+
+    fn bounding_box(&self) -> Rect<T> {
+        let r = Vector::new(self.radius, self.radius);
+
+        Rect::new(self.center - r, self.center + r)
+    }
+}
+
+impl<T: Number> Polygon<T> {
+    pub fn new(vertices: impl IntoIterator<Item = Point<T>>) -> Polygon<T> {
+        Polygon {
+            vertices: vertices.into_iter().collect(),
+        }
+    }
+
+    pub fn vertices(&self) -> &[Point<T>] {
+        &self.vertices
+    }
+
+    // each side as a pair of vertices, including the one from the last vertex
+    // back to the first
+    pub fn edges(&self) -> impl Iterator<Item = (Point<T>, Point<T>)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    // whether `point` is inside, by counting how many edges a line going right
+    // from it crosses - an odd number means it's inside
+    pub fn contains(&self, point: &Point<T>) -> bool {
+        let (x, y) = (point.x.to_f64(), point.y.to_f64());
+
+        self.edges()
+            .filter(|(a, b)| (a.y > point.y) != (b.y > point.y))
+            .filter(|(a, b)| {
+                let (ax, ay, bx, by) = (a.x.to_f64(), a.y.to_f64(), b.x.to_f64(), b.y.to_f64());
+
+                x < ax + (y - ay) * (bx - ax) / (by - ay)
+            })
+            .count()
+            % 2
+            == 1
+    }
+
+    pub fn translate(&mut self, by: Vector<T>) {
+        for vertex in &mut self.vertices {
+            vertex.translate(by);
+        }
+    }
+}
+
+impl<T: Number> HasArea for Polygon<T> {
+    type Unit = T;
+
+    // the shoelace formula, which works for any polygon whose edges don't
+    // cross
+    fn area(&self) -> f64 {
+        let twice_area: f64 = self
+            .edges()
+            .map(|(a, b)| (a.to_f64() - Point::origin()).cross(&(b.to_f64() - Point::origin())))
+            .sum();
+
+        twice_area.abs() / 2.0
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.edges().map(|(a, b)| a.distance_to(&b)).sum()
+    }
+
+    // an empty polygon is a point at the origin
+    fn bounding_box(&self) -> Rect<T> {
+        let mut vertices = self.vertices.iter();
+        let first = vertices.next().copied().unwrap_or_else(Point::origin);
+
+        vertices.fold(Rect::new(first, first), |rect, vertex| {
+            rect.union(&Rect::new(*vertex, *vertex))
+        })
+    }
+}
+
+// Display passes the formatter on to each coordinate, so `{:.1}` applies to
+// all of them
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        self.x.fmt(f)?;
+        write!(f, ", ")?;
+        self.y.fmt(f)?;
+        write!(f, ")")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vector<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<")?;
+        self.x.fmt(f)?;
+        write!(f, ", ")?;
+        self.y.fmt(f)?;
+        write!(f, ">")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Rect<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Rect ")?;
+        self.min.fmt(f)?;
+        write!(f, " to ")?;
+        self.max.fmt(f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Circle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Circle at ")?;
+        self.center.fmt(f)?;
+        write!(f, " with radius ")?;
+        self.radius.fmt(f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Polygon<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Polygon [")?;
+
+        for (i, vertex) in self.vertices.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            vertex.fmt(f)?;
+        }
+
+        write!(f, "]")
+    }
+}
+
+// the sketch in `{:#?}` has 2 cells per unit, and is shrunk to fit in 40
+// cells either way if the shape is too big
+const DEBUG_SKETCH_SCALE: f64 = 2.0;
+const DEBUG_SKETCH_CELLS: usize = 40;
+
+// `{:?}` is the same as a derived Debug, and `{:#?}` follows the fields with
+// a sketch of the shape, so its size and position can be seen at a glance
+impl<T: Number> fmt::Debug for Rect<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rect")
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .finish()?;
+
+        if f.alternate() {
+            write!(
+                f,
+                "\n{}",
+                Canvas::sketch_within(self, DEBUG_SKETCH_SCALE, DEBUG_SKETCH_CELLS)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Number> fmt::Debug for Circle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Circle")
+            .field("center", &self.center)
+            .field("radius", &self.radius)
+            .finish()?;
+
+        if f.alternate() {
+            write!(
+                f,
+                "\n{}",
+                Canvas::sketch_within(self, DEBUG_SKETCH_SCALE, DEBUG_SKETCH_CELLS)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: ToDoc> ToDoc for Point<T> {
+    fn to_doc(&self) -> Doc {
+        Doc::record("Point", [("x", self.x.to_doc()), ("y", self.y.to_doc())])
+    }
+}
+
+impl<T: ToDoc> ToDoc for Vector<T> {
+    fn to_doc(&self) -> Doc {
+        Doc::record("Vector", [("x", self.x.to_doc()), ("y", self.y.to_doc())])
+    }
+}
+
+impl<T: ToDoc> ToDoc for Rect<T> {
+    fn to_doc(&self) -> Doc {
+        Doc::record(
+            "Rect",
+            [("min", self.min.to_doc()), ("max", self.max.to_doc())],
+        )
+    }
+}
+
+impl<T: ToDoc> ToDoc for Circle<T> {
+    fn to_doc(&self) -> Doc {
+        Doc::record(
+            "Circle",
+            [
+                ("center", self.center.to_doc()),
+                ("radius", self.radius.to_doc()),
+            ],
+        )
+    }
+}
+
+impl<T: ToDoc> ToDoc for Polygon<T> {
+    fn to_doc(&self) -> Doc {
+        Doc::record("Polygon", [("vertices", self.vertices.to_doc())])
+    }
+}
@@ -0,0 +1,425 @@
+// A grid of characters to draw shapes on, to replace the fixed-size box that
+// `struct_exercise` used to print for every rectangle.
+//
+// Shapes are drawn in their own coordinates, which the canvas maps onto its
+// cells: the cell in the top left corner is at `origin`, and each unit is
+// `scale` cells wide and tall. Like in `struct_exercise`, y grows downwards:
+//
+//     let mut canvas = Canvas::new(12, 7).with_scale(2.0);
+//
+//     canvas.draw(&Rect::new(Point::new(0.5, 0.5), Point::new(5.0, 3.0)), '#');
+//     canvas.draw(&Circle::new(Point::new(3.0, 1.5), 1.0), 'o');
+//
+//     println!("{canvas}");
+//
+// Anything outside the canvas is clipped before it's rasterised, so the work
+// depends on the size of the canvas rather than of the shape: lines are cut to
+// the part that crosses the canvas, and circles that miss it are skipped.
+// Terminal characters are about twice as tall as they are wide, so shapes look
+// stretched vertically.
+use std::fmt;
+
+use super::{Circle, HasArea, Number, Point, Polygon, Rect};
+
+// circles bigger than this, in cells, are drawn a row and a column at a time
+// rather than walked the whole way round
+const MIDPOINT_MAX_RADIUS: f64 = 4096.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    cells: Vec<char>,
+    origin: Point<f64>,
+    scale: f64,
+    background: char,
+}
+
+// shapes that can be drawn onto a canvas
+pub trait Draw {
+    fn draw(&self, canvas: &mut Canvas, brush: char);
+}
+
+impl Canvas {
+    // a blank canvas, with (0, 0) in the top left cell and a scale of 1
+    pub fn new(width: usize, height: usize) -> Canvas {
+        Canvas {
+            width,
+            height,
+            cells: vec![' '; width * height],
+            origin: Point::origin(),
+            scale: 1.0,
+            background: ' ',
+        }
+    }
+
+    // the number of cells per unit
+    pub fn with_scale(self, scale: f64) -> Canvas {
+        Canvas { scale, ..self }
+    }
+
+    // the point drawn in the top left cell
+    pub fn with_origin<T: Number>(self, origin: Point<T>) -> Canvas {
+        Canvas {
+            origin: Point::new(origin.x.to_f64(), origin.y.to_f64()),
+            ..self
+        }
+    }
+
+    pub fn with_background(self, background: char) -> Canvas {
+        Canvas {
+            cells: vec![background; self.cells.len()],
+            background,
+            ..self
+        }
+    }
+
+    // a canvas just big enough for `shape` and the origin, which is marked
+    // with a `+`, so the shape's size and position are both visible
+    pub fn sketch<S: Draw + HasArea>(shape: &S, scale: f64) -> Canvas {
+        let bounds = shape.bounding_box();
+        let (min, max) = (bounds.min(), bounds.max());
+        let origin = Point::new(min.x.to_f64().min(0.0), min.y.to_f64().min(0.0));
+        let cells = |from: f64, to: f64| ((to - from) * scale).round().max(0.0) as usize + 1;
+        let mut canvas = Canvas::new(
+            cells(origin.x, max.x.to_f64().max(0.0)),
+            cells(origin.y, max.y.to_f64().max(0.0)),
+        )
+        .with_scale(scale)
+        .with_origin(origin);
+
+        canvas.plot(Point::<f64>::origin(), '+');
+        canvas.draw(shape, '#');
+        canvas
+    }
+
+    // a sketch at `scale`, or smaller if that would be more than `cells` wide
+    // or tall, for when the shape's size isn't known up front, as in `{:#?}`
+    pub fn sketch_within<S: Draw + HasArea>(shape: &S, scale: f64, cells: usize) -> Canvas {
+        let bounds = shape.bounding_box();
+        let (min, max) = (bounds.min(), bounds.max());
+        let extent = |min: f64, max: f64| max.max(0.0) - min.min(0.0);
+        let largest =
+            extent(min.x.to_f64(), max.x.to_f64()).max(extent(min.y.to_f64(), max.y.to_f64()));
+        let scale = scale.min(cells.saturating_sub(1) as f64 / largest);
+
+        Canvas::sketch(shape, if scale > 0.0 { scale } else { 1.0 })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // the character in a cell, counting from 0 in the top left
+    pub fn get(&self, column: usize, row: usize) -> Option<char> {
+        (column < self.width && row < self.height).then(|| self.cells[row * self.width + column])
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.fill(self.background);
+    }
+
+    // the cell a point falls in, which may be off the canvas. Points too far
+    // away for an i64 are clamped to i64::MIN or i64::MAX
+    pub fn cell<T: Number>(&self, point: Point<T>) -> (i64, i64) {
+        let (x, y) = self.position(point);
+
+        (x.round() as i64, y.round() as i64)
+    }
+
+    // where a point is, in cells, before it's rounded to one
+    fn position<T: Number>(&self, point: Point<T>) -> (f64, f64) {
+        (
+            (point.x.to_f64() - self.origin.x) * self.scale,
+            (point.y.to_f64() - self.origin.y) * self.scale,
+        )
+    }
+
+    fn set(&mut self, (x, y): (i64, i64), brush: char) {
+        if (0..self.width as i64).contains(&x) && (0..self.height as i64).contains(&y) {
+            self.cells[y as usize * self.width + x as usize] = brush;
+        }
+    }
+
+    // set the cell a position rounds to, checking it's on the canvas before
+    // converting it to integers
+    fn set_position(&mut self, (x, y): (f64, f64), brush: char) {
+        let (x, y) = (x.round(), y.round());
+
+        if (0.0..self.width as f64).contains(&x) && (0.0..self.height as f64).contains(&y) {
+            self.set((x as i64, y as i64), brush);
+        }
+    }
+
+    pub fn plot<T: Number>(&mut self, point: Point<T>, brush: char) {
+        self.set_position(self.position(point), brush);
+    }
+
+    pub fn line<T: Number>(&mut self, from: Point<T>, to: Point<T>, brush: char) {
+        self.line_positions(self.position(from), self.position(to), brush);
+    }
+
+    fn line_positions(&mut self, from: (f64, f64), to: (f64, f64), brush: char) {
+        if let Some((from, to)) = self.clip(from, to) {
+            let round = |(x, y): (f64, f64)| (x.round() as i64, y.round() as i64);
+
+            self.line_cells(round(from), round(to), brush);
+        }
+    }
+
+    // the part of the line from `from` to `to` that's on the canvas, or None
+    // if it misses it. Cells are centred on whole numbers, so the canvas'
+    // edges are half a cell beyond the first and last ones
+    fn clip(&self, from: (f64, f64), to: (f64, f64)) -> Option<((f64, f64), (f64, f64))> {
+        let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+
+        if ![from.0, from.1, dx, dy]
+            .iter()
+            .all(|value| value.is_finite())
+        {
+            return None;
+        }
+
+        let edges = (self.width as f64 - 0.5, self.height as f64 - 0.5);
+
+        if dy.abs() > dx.abs() {
+            let swap = |(x, y): (f64, f64)| (y, x);
+
+            Canvas::clip_along(swap(from), swap(to), swap(edges))
+                .map(|(from, to)| (swap(from), swap(to)))
+        } else {
+            Canvas::clip_along(from, to, edges)
+        }
+    }
+
+    // clip a line that's no steeper than 45° to the u axis, i.e. (u, v) is
+    // (x, y) or (y, x). Rather than moving both ends in from far away, which
+    // loses the precision the canvas needs, the clipped u range is found first,
+    // and v worked out from u
+    fn clip_along(
+        from: (f64, f64),
+        to: (f64, f64),
+        (u_edge, v_edge): (f64, f64),
+    ) -> Option<((f64, f64), (f64, f64))> {
+        let (start, end) = if from.0 <= to.0 {
+            (from, to)
+        } else {
+            (to, from)
+        };
+        let du = end.0 - start.0;
+        let slope = if du == 0.0 {
+            0.0
+        } else {
+            (end.1 - start.1) / du
+        };
+        let (mut low, mut high) = (start.0.max(-0.5), end.0.min(u_edge));
+
+        if slope == 0.0 {
+            if !(-0.5..=v_edge).contains(&start.1) {
+                return None;
+            }
+        } else {
+            // where the line crosses the top and bottom edges
+            let top = start.0 + (-0.5 - start.1) / slope;
+            let bottom = start.0 + (v_edge - start.1) / slope;
+
+            low = low.max(top.min(bottom));
+            high = high.min(top.max(bottom));
+        }
+
+        if low > high {
+            return None;
+        }
+
+        // ends that weren't clipped are kept exactly as they were
+        let at = |u: f64, unclipped: (f64, f64)| {
+            if u == unclipped.0 {
+                unclipped
+            } else {
+                (u, start.1 + (u - start.0) * slope)
+            }
+        };
+        let (low, high) = (at(low, start), at(high, end));
+
+        Some(if from.0 <= to.0 {
+            (low, high)
+        } else {
+            (high, low)
+        })
+    }
+
+    pub fn draw(&mut self, shape: &impl Draw, brush: char) {
+        shape.draw(self, brush);
+    }
+
+    // Bresenham's line algorithm, which steps one cell at a time along the
+    // longer axis, and keeps track of how far the line has drifted along the
+    // other one to know when to step along that too. It only uses integers
+    fn line_cells(&mut self, (mut x, mut y): (i64, i64), (x1, y1): (i64, i64), brush: char) {
+        let (dx, dy) = ((x1 - x).abs(), -(y1 - y).abs());
+        let (step_x, step_y) = ((x1 - x).signum(), (y1 - y).signum());
+        let mut error = dx + dy;
+
+        loop {
+            self.set((x, y), brush);
+
+            if (x, y) == (x1, y1) {
+                break;
+            }
+
+            let doubled = 2 * error;
+
+            if doubled >= dy {
+                error += dy;
+                x += step_x;
+            }
+
+            if doubled <= dx {
+                error += dx;
+                y += step_y;
+            }
+        }
+    }
+
+    fn circle_positions(&mut self, (cx, cy): (f64, f64), radius: f64, brush: char) {
+        let radius = radius.round();
+
+        if self.cells.is_empty() || ![cx, cy, radius].iter().all(|value| value.is_finite()) {
+            return;
+        }
+
+        // the circle misses the canvas if even the nearest cell is further
+        // than a radius from the centre, or even the furthest one is closer
+        let (last_x, last_y) = ((self.width - 1) as f64, (self.height - 1) as f64);
+        let nearest = (cx - cx.clamp(0.0, last_x)).hypot(cy - cy.clamp(0.0, last_y));
+        let furthest = cx.max(last_x - cx).hypot(cy.max(last_y - cy));
+
+        if nearest > radius + 1.0 || furthest < radius - 1.0 {
+            return;
+        }
+
+        if radius <= MIDPOINT_MAX_RADIUS {
+            // the centre is within a radius of the canvas, so fits in an i64
+            self.circle_cells((cx.round() as i64, cy.round() as i64), radius as i64, brush);
+        } else {
+            self.circle_scan((cx, cy), radius, brush);
+        }
+    }
+
+    // the midpoint circle algorithm: walk an eighth of the circle, from the
+    // right-most cell towards the diagonal, and mirror each cell into the
+    // other seven eighths. `decision` tracks whether the midpoint between the
+    // two candidates for the next cell is inside the circle or not
+    fn circle_cells(&mut self, (cx, cy): (i64, i64), radius: i64, brush: char) {
+        let (mut x, mut y) = (radius, 0);
+        let mut decision = 1 - radius;
+
+        while x >= y {
+            for (dx, dy) in [
+                (x, y),
+                (y, x),
+                (-y, x),
+                (-x, y),
+                (-x, -y),
+                (-y, -x),
+                (y, -x),
+                (x, -y),
+            ] {
+                self.set((cx + dx, cy + dy), brush);
+            }
+
+            y += 1;
+
+            if decision < 0 {
+                decision += 2 * y + 1;
+            } else {
+                x -= 1;
+                decision += 2 * (y - x) + 1;
+            }
+        }
+    }
+
+    // for circles too big to walk the whole way round: where the circle
+    // crosses each column and each row of the canvas. Doing both keeps the
+    // steep parts joined up, as the midpoint algorithm does with its octants
+    fn circle_scan(&mut self, (cx, cy): (f64, f64), radius: f64, brush: char) {
+        // (r - d)(r + d) rather than r² - d², which overflows for huge radii
+        let half_chord = |offset: f64| ((radius - offset) * (radius + offset)).sqrt();
+
+        for column in 0..self.width {
+            let dx = column as f64 - cx;
+
+            if dx.abs() <= radius {
+                let dy = half_chord(dx);
+
+                self.set_position((column as f64, cy - dy), brush);
+                self.set_position((column as f64, cy + dy), brush);
+            }
+        }
+
+        for row in 0..self.height {
+            let dy = row as f64 - cy;
+
+            if dy.abs() <= radius {
+                let dx = half_chord(dy);
+
+                self.set_position((cx - dx, row as f64), brush);
+                self.set_position((cx + dx, row as f64), brush);
+            }
+        }
+    }
+}
+
+impl<T: Number> Draw for Point<T> {
+    fn draw(&self, canvas: &mut Canvas, brush: char) {
+        canvas.plot(*self, brush);
+    }
+}
+
+impl<T: Number> Draw for Rect<T> {
+    fn draw(&self, canvas: &mut Canvas, brush: char) {
+        let ((x0, y0), (x1, y1)) = (canvas.position(self.min()), canvas.position(self.max()));
+
+        canvas.line_positions((x0, y0), (x1, y0), brush);
+        canvas.line_positions((x1, y0), (x1, y1), brush);
+        canvas.line_positions((x1, y1), (x0, y1), brush);
+        canvas.line_positions((x0, y1), (x0, y0), brush);
+    }
+}
+
+impl<T: Number> Draw for Circle<T> {
+    fn draw(&self, canvas: &mut Canvas, brush: char) {
+        let radius = self.radius().to_f64() * canvas.scale;
+
+        canvas.circle_positions(canvas.position(self.center()), radius, brush);
+    }
+}
+
+impl<T: Number> Draw for Polygon<T> {
+    fn draw(&self, canvas: &mut Canvas, brush: char) {
+        for (from, to) in self.edges() {
+            canvas.line(from, to, brush);
+        }
+    }
+}
+
+// one line per row, without trailing spaces
+impl fmt::Display for Canvas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.cells.chunks(self.width.max(1)).enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            let row: String = row.iter().collect();
+
+            write!(f, "{}", row.trim_end_matches(' '))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,546 @@
+use rbe_registry::Chapter;
+use std::cell::Cell;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::rc::Rc;
+
+pub mod c_enum;
+pub mod events;
+pub mod geometry;
+pub mod list;
+pub mod vm;
+
+use events::{Event, EventBus, EventKind, ScrollDir};
+use geometry::{HasArea, Point, Rect};
+use list::List;
+use vm::{Program, Vm};
+
+fn tuple_structs() {
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct MyTuple(i32, char, String);
+
+    let tup_1 = MyTuple(42, 'h', String::from("foo"));
+    println!("&tup_1: {:?}", &tup_1);
+    println!("tup_1.0: {}", tup_1.0);
+
+    let tup_2 = MyTuple {
+        2: String::from("foo"),
+        1: 'h',
+        0: 42,
+    };
+    println!("&tup_2: {:?}", &tup_2);
+    println!("tup_2.0: {}", tup_2.0);
+
+    println!();
+}
+
+fn c_structs() {
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Thing<'a> {
+        foo: String,
+        bar: &'a str,
+    }
+
+    let not_foo = "foo".to_string();
+    let bar = "hey";
+    let thing = Thing { foo: not_foo, bar };
+
+    println!("{:?}", &thing);
+    println!();
+}
+
+fn unit_structs() {
+    #[derive(Debug)]
+    struct MyUnit;
+
+    impl std::fmt::Display for MyUnit {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "aw I'm a unit: {:?}", &self)
+        }
+    }
+
+    let unit = MyUnit;
+    println!("{unit}");
+    println!();
+}
+
+fn struct_update_syntax() {
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Thing<T> {
+        a: T,
+        b: T,
+        c: T,
+    }
+
+    let thing_1 = Thing { a: 1, b: 2, c: 3 };
+    let thing_2 = Thing { b: 3, ..thing_1 };
+
+    println!("thing 1: {:?}", &thing_1);
+    println!("thing 2: {:?}", &thing_2);
+    assert!(thing_1.a == thing_2.a);
+    assert_eq!(thing_1.c, thing_2.c);
+    println!();
+}
+
+fn struct_destructuring() {
+    struct Thing<T> {
+        a: T,
+        b: T,
+        c: T,
+    }
+
+    let thing = Thing { a: 1, b: 2, c: 3 };
+    let Thing {
+        a: another_a,
+        b: hello,
+        c,
+    } = thing;
+
+    println!("another_a: {another_a}, hello: {hello}, c: {c}");
+    println!();
+}
+
+fn struct_exercise() {
+    // the exercise's `Point<T = f32>` and `Rectangle` have grown into
+    // `geometry::Point<T = f64>` and `geometry::Rect`, which chapters 9, 14 and
+    // 19 share
+    let top_left = Point::new(1.0, 2.3);
+    let rect = Rect::new(top_left, Point::new(3.0, 5.2));
+    let square = Rect::square(Point::new(1.0, 4.4), 3.0);
+
+    println!("rect: {:?}", &rect);
+    println!("rect area: {:.2}", rect.area());
+
+    println!("square: {:?}", &square);
+    println!("square area: {}", square.area());
+    println!();
+
+    // the old `Debug` impl drew the same box for every rectangle. `{:#?}`
+    // sketches each one where it actually is, with the origin as a `+`, at 2
+    // cells per unit
+    println!("rect: {rect:#?}");
+    println!("square: {square:#?}");
+    println!();
+
+    // a rectangle is stored by its min and max corners, so the area can't go
+    // negative when the corners are given the other way around
+    let swapped = Rect::new(Point::new(3.0, 5.2), top_left);
+
+    println!("swapped corners: {swapped}");
+    println!("swapped area: {:.2}", swapped.area());
+    println!("same rectangle: {}", swapped == rect);
+    println!();
+}
+
+fn enum_variants() {
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    enum MyEnum {
+        Unit,
+        Tuple(i32),
+        CLike { foo: String },
+    }
+
+    let _x = MyEnum::Unit; // <= type is MyEnum
+
+    println!("{:?}", MyEnum::Unit);
+    println!("{:?}", MyEnum::Tuple(3));
+    println!(
+        "{:?}",
+        MyEnum::CLike {
+            foo: String::from("bar")
+        }
+    );
+    println!();
+}
+
+fn enum_matching() {
+    // `Event` and `ScrollDir` live in `events`, where they've grown an event
+    // bus - see `event_bus`
+    fn handle_event(event: Event) {
+        match event {
+            Event::MouseClick(x, y) => println!("clicked at ({x}, {y})"),
+            Event::KeyPress(x) => println!("pressed key {x}"),
+            Event::Scroll(dir) => println!("scrolled {dir:?}"),
+        }
+    }
+
+    let mouse_click = Event::MouseClick(32, 4);
+    let key_press = Event::KeyPress('m');
+    let scroll = Event::Scroll(ScrollDir::Right);
+
+    handle_event(mouse_click);
+    handle_event(key_press);
+    handle_event(scroll);
+    println!();
+}
+
+fn event_bus() {
+    // the line a pretend document is scrolled to, shared by the handlers
+    let line = Rc::new(Cell::new(0));
+
+    let mut bus = EventBus::new();
+    let logger = bus.on_any(|event, _| println!("event: {event}"));
+
+    // j and k are shortcuts, which emit scroll events rather than scrolling
+    // themselves
+    bus.on(EventKind::KeyPress, |event, emitter| match event {
+        Event::KeyPress('j') => emitter.emit(Event::Scroll(ScrollDir::Down)),
+        Event::KeyPress('k') => emitter.emit(Event::Scroll(ScrollDir::Up)),
+        Event::KeyPress(key) => println!("  pressed key {key}"),
+        _ => {}
+    });
+    bus.on(EventKind::Scroll, {
+        let line = Rc::clone(&line);
+
+        move |event, _| match event {
+            Event::Scroll(ScrollDir::Down) => line.set(line.get() + 1),
+            Event::Scroll(ScrollDir::Up) => line.set(line.get() - 1),
+            Event::Scroll(dir) => println!("  can't scroll {dir}"),
+            _ => {}
+        }
+    });
+    bus.on(EventKind::MouseClick, |event, _| {
+        if let Event::MouseClick(x, y) = event {
+            println!("  clicked at ({x}, {y})");
+        }
+    });
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("scripts/events.txt");
+
+    match File::open(&path).map(|file| bus.run_script(BufReader::new(file))) {
+        Ok(Ok(handled)) => println!("handled {handled} events, now on line {}", line.get()),
+        Ok(Err(error)) => println!("script error: {error}"),
+        Err(error) => println!("could not open {}: {error}", path.display()),
+    }
+
+    // without the logger, only the handlers' own output is printed
+    bus.off(logger);
+    bus.push(Event::KeyPress('x'));
+    bus.run();
+
+    match bus.run_script("key j\nscroll sideways\n".as_bytes()) {
+        Ok(handled) => println!("handled {handled} events"),
+        Err(error) => println!("script error: {error}"),
+    }
+
+    println!("now on line {}", line.get());
+    println!();
+}
+
+fn enum_aliases() {
+    #[derive(Debug)]
+    enum IReallyLoveCarpeting {
+        A,
+        B,
+        C,
+    }
+
+    // alias IReallyLoveCarpeting
+    type Carpeting = IReallyLoveCarpeting;
+
+    // variants are accessible via the alias
+    let a = Carpeting::A;
+    let b = Carpeting::B;
+    let c = Carpeting::C;
+
+    println!("{a:?}, {b:?}, {c:?}");
+    println!();
+}
+
+fn enum_self() {
+    // `Operations` has grown into the instructions for a stack machine - see
+    // `stack_machine`
+    #[derive(Debug)]
+    enum Operations {
+        Sum,
+        Product,
+    }
+
+    impl Operations {
+        fn do_operation(&self, x: i32, y: i32) -> i32 {
+            match &self {
+                Self::Sum => x + y,
+                Self::Product => x * y,
+            }
+        }
+    }
+
+    let sum = Operations::Sum;
+    let product = Operations::Product;
+    let x = 3;
+    let y = 5;
+
+    println!("sum({x}, {y}) = {}", sum.do_operation(x, y));
+    println!("product({x}, {y}) = {}", product.do_operation(x, y));
+    println!();
+}
+
+fn stack_machine() {
+    let source = "
+        ; prints 5!, computed by a subroutine
+            push 5
+            call factorial
+            print
+            halt
+
+        ; replaces n on the stack with n!
+        factorial:
+            push 1      ; the result so far
+            swap
+        loop:           ; [result, n]
+            dup
+            jz done
+            swap
+            over
+            mul
+            swap
+            push 1
+            sub
+            jmp loop
+        done:
+            pop
+            ret
+    ";
+
+    let program: Program = match source.parse() {
+        Ok(program) => program,
+        Err(error) => {
+            println!("could not assemble: {error}");
+            return;
+        }
+    };
+    let mut vm = Vm::new(&program);
+
+    match vm.run() {
+        Ok(()) => println!("output: {:?} after {} steps", vm.output(), vm.steps()),
+        Err(error) => println!("error: {error}"),
+    }
+
+    // the disassembly names jump targets by their address
+    println!("disassembled:\n{program}");
+    println!();
+
+    // tracing prints the stack after each instruction
+    let program: Program = "push 2\npush 3\nadd\npush 4\nmul\nprint"
+        .parse()
+        .expect("a valid program");
+
+    if let Err(error) = Vm::new(&program).trace(std::io::stdout()) {
+        println!("error: {error}");
+    }
+
+    println!();
+
+    // invalid programs stop with an error, rather than a panic
+    for source in [
+        "push 1\nadd",
+        "push 1\npush 0\ndiv",
+        "jmp 10",
+        "loop:\njmp loop",
+    ] {
+        let program: Program = source.parse().expect("a valid program");
+        let mut vm = Vm::new(&program).with_step_limit(100);
+
+        if let Err(error) = vm.run() {
+            println!("{:?}: {error}", source.replace('\n', "; "));
+        }
+    }
+
+    if let Err(error) = "push 1\njmp nowhere".parse::<Program>() {
+        println!("could not assemble: {error}");
+    }
+
+    println!();
+}
+
+#[derive(Debug)]
+enum TopLevelOne {
+    OneA,
+    OneB,
+}
+#[derive(Debug)]
+enum TopLevelTwo {
+    TwoA,
+    TwoB,
+}
+
+fn enum_use() {
+    // allow variants in the outer scope to be used without manually scoping
+    // each variant
+    use crate::TopLevelOne::{OneA, OneB as HeyB};
+    use crate::TopLevelTwo::*;
+
+    let one_a = OneA;
+    let one_b = HeyB;
+    let two_a = TwoA;
+    let two_b = TwoB;
+
+    println!("one_a: {one_a:?}");
+    println!("one_b: {one_b:?}");
+    println!("two_a: {two_a:?}");
+    println!("two_b: {two_b:?}");
+    println!();
+}
+
+fn enum_discriminators() {
+    // `c_enum!` declares the enums as they'd be written without it, and adds
+    // conversions from numbers and names back to variants
+    c_enum! {
+        enum ImplicitDiscriminator {
+            First,
+            Second,
+        }
+    }
+
+    c_enum! {
+        enum ExplicitDiscriminator {
+            First = 999,
+            Second = 1_000,
+        }
+    }
+
+    println!(
+        "ImplicitDiscriminator::First: {}",
+        ImplicitDiscriminator::First as i32
+    );
+    println!(
+        "ImplicitDiscriminator::Second: {}",
+        ImplicitDiscriminator::Second as i32
+    );
+    println!(
+        "ExplicitDiscriminator::First: {}",
+        ExplicitDiscriminator::First as i32
+    );
+    println!(
+        "ExplicitDiscriminator::Second: {}",
+        ExplicitDiscriminator::Second as i32
+    );
+    println!();
+
+    // going the other way can fail, so it's `TryFrom` rather than `as`. The
+    // bytes might have been read from a file or a socket
+    for byte in [1_u8, 0, 7] {
+        match ImplicitDiscriminator::try_from(byte) {
+            Ok(variant) => println!("byte {byte}: ImplicitDiscriminator::{variant}"),
+            Err(error) => println!("byte {byte}: {error}"),
+        }
+    }
+
+    println!(
+        "1000 as ExplicitDiscriminator: {:?}",
+        ExplicitDiscriminator::try_from(1_000)
+    );
+    // 1_000 as u8 wraps around to 232, which isn't a discriminant
+    println!(
+        "232 as ExplicitDiscriminator: {:?}",
+        ExplicitDiscriminator::try_from(232_u8)
+    );
+
+    // and by name
+    for name in ["First", "first"] {
+        match name.parse::<ExplicitDiscriminator>() {
+            Ok(variant) => println!("{name:?}: {} = {}", variant.name(), variant as i32),
+            Err(error) => println!("{name:?}: {error}"),
+        }
+    }
+
+    println!("all: {:?}", ImplicitDiscriminator::ALL);
+
+    for variant in ExplicitDiscriminator::iter() {
+        println!("{variant:>8} = {}", variant as i32);
+    }
+
+    println!();
+}
+
+fn enum_linked_list() {
+    let mut list = List::new();
+
+    list = list.prepend(3);
+    list = list.prepend(4);
+    list = list.prepend(5);
+
+    println!("list: {}", &list);
+    println!("list length: {}", &list.len());
+    println!();
+
+    // the list is generic, and can be built from any iterator
+    let words: List<&str> = "the quick brown fox".split(' ').collect();
+
+    println!("words: {:?}", &words);
+    println!("words.head(): {:?}", words.head());
+    println!("words.tail(): {:?}", words.tail());
+    println!("words reversed: {}", words.clone().reverse());
+    println!("words appended to words: {:?}", words.clone().append(words));
+    println!();
+}
+
+fn enum_shared_list() {
+    use list::rc;
+
+    // `rc::List::prepend` borrows the list instead of consuming it, so both
+    // `a` and `b` can share `shared` as their tail
+    let shared = rc::List::new().prepend(1).prepend(2);
+    let a = shared.prepend(3);
+    let b = shared.prepend(4);
+
+    println!("shared: {}", &shared);
+    println!("a: {}", &a);
+    println!("b: {}", &b);
+    println!("lists pointing at shared: {}", shared.strong_count());
+    println!(
+        "a's tail is shared: {}",
+        a.tail().is_some_and(|tail| tail.ptr_eq(&shared))
+    );
+
+    drop(a);
+    drop(b);
+
+    println!(
+        "lists pointing at shared after dropping a and b: {}",
+        shared.strong_count()
+    );
+    println!();
+}
+
+const I_AM_GLOBAL: &str = "I am global!";
+
+fn const_definitions() {
+    const I_AM_LOCAL: &str = "I am local!";
+
+    println!("{}", I_AM_GLOBAL);
+    println!("{}", I_AM_LOCAL);
+}
+
+pub const CHAPTER: Chapter = rbe_registry::chapter! {
+    number: 3,
+    name: "custom_types",
+    examples: {
+        "structs": [
+            tuple_structs,
+            c_structs,
+            unit_structs,
+            struct_update_syntax,
+            struct_destructuring,
+            struct_exercise,
+        ],
+        "enums": [
+            enum_variants,
+            enum_matching,
+            event_bus,
+            enum_aliases,
+            enum_self,
+            stack_machine,
+            enum_use,
+            enum_discriminators,
+            enum_linked_list,
+            enum_shared_list,
+        ],
+        "constants": [const_definitions],
+    },
+};
@@ -0,0 +1,301 @@
+// A generic, singly linked cons list, grown from the `enum List` example.
+//
+// The enum from the example lives on as `Node` - a list is a `Node` together
+// with its length, so that `len` doesn't need to walk the whole list.
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FusedIterator;
+use std::mem;
+
+pub mod rc;
+
+enum Node<T> {
+    // a node in a linked list containing a value, and a pointer to the rest of
+    // the list
+    Cons(T, Box<List<T>>),
+    // the end of the linked list
+    Nil,
+}
+
+pub struct List<T> {
+    node: Node<T>,
+    len: usize,
+}
+
+impl<T> List<T> {
+    // create an empty list
+    pub fn new() -> List<T> {
+        List {
+            node: Node::Nil,
+            len: 0,
+        }
+    }
+
+    // prepend a value to the current list and return it
+    pub fn prepend(self, elem: T) -> List<T> {
+        List {
+            len: self.len + 1,
+            node: Node::Cons(elem, Box::new(self)),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // the first value in the list
+    pub fn head(&self) -> Option<&T> {
+        match self.node {
+            // `ref` borrows the value instead of moving it out of `self`
+            Node::Cons(ref head, _) => Some(head),
+            Node::Nil => None,
+        }
+    }
+
+    pub fn head_mut(&mut self) -> Option<&mut T> {
+        match self.node {
+            Node::Cons(ref mut head, _) => Some(head),
+            Node::Nil => None,
+        }
+    }
+
+    // everything after the first value in the list
+    pub fn tail(&self) -> Option<&List<T>> {
+        match self.node {
+            Node::Cons(_, ref tail) => Some(tail),
+            Node::Nil => None,
+        }
+    }
+
+    // remove the first value from the list, leaving the tail in its place
+    pub fn pop_front(&mut self) -> Option<T> {
+        match mem::replace(&mut self.node, Node::Nil) {
+            Node::Cons(head, mut tail) => {
+                self.node = mem::replace(&mut tail.node, Node::Nil);
+                self.len -= 1;
+
+                Some(head)
+            }
+            Node::Nil => None,
+        }
+    }
+
+    pub fn reverse(mut self) -> List<T> {
+        let mut reversed = List::new();
+
+        while let Some(elem) = self.pop_front() {
+            reversed = reversed.prepend(elem);
+        }
+
+        reversed
+    }
+
+    // add all the values in `other` to the end of the list
+    pub fn append(self, other: List<T>) -> List<T> {
+        // prepending the values in reverse order leaves them in their
+        // original order in front of `other`
+        self.reverse().into_iter().fold(other, List::prepend)
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { list: self }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut { list: Some(self) }
+    }
+}
+
+// the default `Drop` would drop each `Box` in the list from inside the drop of
+// the `Box` before it, and overflow the stack for long lists. Instead, each
+// node is detached from the list before it is dropped
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        let mut node = mem::replace(&mut self.node, Node::Nil);
+
+        while let Node::Cons(_, mut tail) = node {
+            node = mem::replace(&mut tail.node, Node::Nil);
+        }
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        List::new()
+    }
+}
+
+// like `Drop`, deriving the rest of these traits would recurse once for each
+// value in the list, so they're all implemented using iterators instead
+impl<T: Clone> Clone for List<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+// 5 4 3 Nil
+impl<T: fmt::Display> fmt::Display for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for elem in self {
+            // pass the formatter along so that e.g. `{:.2}` applies to each
+            // value
+            fmt::Display::fmt(elem, f)?;
+            write!(f, " ")?;
+        }
+
+        write!(f, "Nil")
+    }
+}
+
+// [5, 4, 3]
+impl<T: fmt::Debug> fmt::Debug for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other)
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+// lists are ordered lexicographically, the same as slices
+impl<T: PartialOrd> PartialOrd for List<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other)
+    }
+}
+
+impl<T: Ord> Ord for List<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other)
+    }
+}
+
+impl<T: Hash> Hash for List<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // include the length so that e.g. lists of lists that contain the same
+        // values split up differently don't hash the same
+        state.write_usize(self.len);
+
+        for elem in self {
+            elem.hash(state);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        iter.into_iter().fold(List::new(), List::prepend).reverse()
+    }
+}
+
+pub struct Iter<'a, T> {
+    list: &'a List<T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.list.node {
+            Node::Cons(ref head, ref tail) => {
+                self.list = tail;
+
+                Some(head)
+            }
+            Node::Nil => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+impl<T> FusedIterator for Iter<'_, T> {}
+
+pub struct IterMut<'a, T> {
+    list: Option<&'a mut List<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let list = self.list.take()?;
+
+        match list.node {
+            Node::Cons(ref mut head, ref mut tail) => {
+                self.list = Some(tail);
+
+                Some(head)
+            }
+            Node::Nil => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.as_ref().map_or(0, |list| list.len);
+
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+pub struct IntoIter<T> {
+    list: List<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut List<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
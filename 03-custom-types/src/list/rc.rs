@@ -0,0 +1,217 @@
+// A persistent variant of `List`, where lists share their tails.
+//
+// `prepend` takes `&self` and leaves the original list untouched. Both lists
+// point at the same nodes through an `Rc`, so prepending and taking the tail
+// are O(1), and no values are ever copied:
+//
+//     shared = 2 -> 1 -> Nil
+//     a = 3 -> shared
+//     b = 4 -> shared
+//
+// Values in a shared list can't be moved out or mutated, so there's no
+// `pop_front` or `iter_mut` here.
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FusedIterator;
+use std::rc::Rc;
+
+struct Node<T> {
+    elem: T,
+    next: List<T>,
+}
+
+pub struct List<T> {
+    // `None` is the end of the list
+    head: Option<Rc<Node<T>>>,
+    len: usize,
+}
+
+impl<T> List<T> {
+    pub fn new() -> List<T> {
+        List { head: None, len: 0 }
+    }
+
+    // create a new list with `elem` in front of this one, sharing all of this
+    // list's nodes
+    pub fn prepend(&self, elem: T) -> List<T> {
+        List {
+            head: Some(Rc::new(Node {
+                elem,
+                next: self.clone(),
+            })),
+            len: self.len + 1,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.elem)
+    }
+
+    // the tail is shared with this list - `.cloned()` it to keep it around
+    // after this list is dropped
+    pub fn tail(&self) -> Option<&List<T>> {
+        self.head.as_ref().map(|node| &node.next)
+    }
+
+    // the number of lists pointing at the first node of this list, including
+    // this one, or 0 for an empty list
+    pub fn strong_count(&self) -> usize {
+        self.head.as_ref().map_or(0, Rc::strong_count)
+    }
+
+    // whether both lists are made up of the very same nodes
+    pub fn ptr_eq(&self, other: &List<T>) -> bool {
+        match (&self.head, &other.head) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { list: self }
+    }
+}
+
+// cloning a list only clones the `Rc` pointing at its first node, so `T`
+// doesn't need to be `Clone`
+impl<T> Clone for List<T> {
+    fn clone(&self) -> Self {
+        List {
+            head: self.head.clone(),
+            len: self.len,
+        }
+    }
+}
+
+// as with `list::List`, the default `Drop` would recurse once for each node.
+// Nodes are dropped one at a time instead, stopping at the first node that is
+// still shared with another list
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        let mut head = self.head.take();
+
+        while let Some(node) = head {
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => head = node.next.head.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        List::new()
+    }
+}
+
+// 5 4 3 Nil
+impl<T: fmt::Display> fmt::Display for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for elem in self {
+            fmt::Display::fmt(elem, f)?;
+            write!(f, " ")?;
+        }
+
+        write!(f, "Nil")
+    }
+}
+
+// [5, 4, 3]
+impl<T: fmt::Debug> fmt::Debug for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        // lists sharing the same nodes are equal without comparing any values
+        self.ptr_eq(other) || (self.len == other.len && self.iter().eq(other))
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+impl<T: PartialOrd> PartialOrd for List<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other)
+    }
+}
+
+impl<T: Ord> Ord for List<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other)
+    }
+}
+
+impl<T: Hash> Hash for List<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+
+        for elem in self {
+            elem.hash(state);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        // nodes can't be changed once they're shared, so the list is built
+        // from the back
+        let elems: Vec<T> = iter.into_iter().collect();
+
+        elems
+            .into_iter()
+            .rev()
+            .fold(List::new(), |list, elem| list.prepend(elem))
+    }
+}
+
+impl<T> From<super::List<T>> for List<T> {
+    fn from(list: super::List<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+pub struct Iter<'a, T> {
+    list: &'a List<T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.list.head.as_ref()?;
+
+        self.list = &node.next;
+
+        Some(&node.elem)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,418 @@
+// A stack machine, grown from `enum_self`'s `Operations`, which could add or
+// multiply two numbers. Here, an `Instruction` takes its operands from a
+// stack and pushes its result back, and a program is a list of instructions
+// that can jump around and call subroutines:
+//
+//     let program: Program = "push 2\npush 3\nadd\nprint".parse()?;
+//     let mut vm = Vm::new(&program);
+//
+//     vm.run()?;
+//     vm.output(); // [5]
+//
+// Programs are written in a small assembly language - see `asm` - and
+// printing a `Program` disassembles it back into that language.
+//
+// Every instruction is checked before it runs: popping from an empty stack,
+// jumping outside the program, dividing by zero and overflowing are all
+// errors, rather than panics, and the error says where it happened. `step`
+// runs a single instruction, and `trace` runs the whole program, writing the
+// stack after each instruction.
+use std::error;
+use std::fmt;
+use std::io::{self, Write};
+
+pub mod asm;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    Push(i64),
+    Pop,
+    // copy the top value
+    Dup,
+    // copy the value below the top
+    Over,
+    // swap the top two values
+    Swap,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    // comparisons push 1 if true, and 0 if false
+    Eq,
+    Lt,
+    Gt,
+    Jump(usize),
+    // pop a value, and jump if it's 0
+    JumpIfZero(usize),
+    // pop a value, and jump if it isn't 0
+    JumpIfNotZero(usize),
+    // jump, remembering where to return to
+    Call(usize),
+    Ret,
+    // pop a value into the output
+    Print,
+    Halt,
+}
+
+impl Instruction {
+    // the name the assembler reads
+    pub fn name(&self) -> &'static str {
+        match self {
+            Instruction::Push(_) => "push",
+            Instruction::Pop => "pop",
+            Instruction::Dup => "dup",
+            Instruction::Over => "over",
+            Instruction::Swap => "swap",
+            Instruction::Add => "add",
+            Instruction::Sub => "sub",
+            Instruction::Mul => "mul",
+            Instruction::Div => "div",
+            Instruction::Rem => "rem",
+            Instruction::Eq => "eq",
+            Instruction::Lt => "lt",
+            Instruction::Gt => "gt",
+            Instruction::Jump(_) => "jmp",
+            Instruction::JumpIfZero(_) => "jz",
+            Instruction::JumpIfNotZero(_) => "jnz",
+            Instruction::Call(_) => "call",
+            Instruction::Ret => "ret",
+            Instruction::Print => "print",
+            Instruction::Halt => "halt",
+        }
+    }
+
+    // the address this instruction can jump to, if any
+    pub fn target(&self) -> Option<usize> {
+        match *self {
+            Instruction::Jump(target)
+            | Instruction::JumpIfZero(target)
+            | Instruction::JumpIfNotZero(target)
+            | Instruction::Call(target) => Some(target),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self, self.target()) {
+            (Instruction::Push(value), _) => write!(f, "push {value}"),
+            (_, Some(target)) => write!(f, "{} {target}", self.name()),
+            _ => f.write_str(self.name()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Program {
+    instructions: Vec<Instruction>,
+}
+
+impl Program {
+    pub fn new(instructions: Vec<Instruction>) -> Program {
+        Program { instructions }
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+}
+
+impl From<Vec<Instruction>> for Program {
+    fn from(instructions: Vec<Instruction>) -> Program {
+        Program::new(instructions)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VmErrorKind {
+    StackUnderflow,
+    // a jump or call to an address past the end of the program
+    BadJump(usize),
+    DivisionByZero,
+    Overflow,
+    ReturnWithoutCall,
+    // the program ran for more steps than the VM's limit
+    StepLimit(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VmError {
+    // the address of the instruction that failed
+    pub address: usize,
+    pub kind: VmErrorKind,
+}
+
+impl fmt::Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "at address {}: ", self.address)?;
+
+        match self.kind {
+            VmErrorKind::StackUnderflow => write!(f, "stack underflow"),
+            VmErrorKind::BadJump(target) => write!(f, "jump to {target}, outside the program"),
+            VmErrorKind::DivisionByZero => write!(f, "division by zero"),
+            VmErrorKind::Overflow => write!(f, "arithmetic overflow"),
+            VmErrorKind::ReturnWithoutCall => write!(f, "return without a call"),
+            VmErrorKind::StepLimit(limit) => write!(f, "stopped after {limit} steps"),
+        }
+    }
+}
+
+impl error::Error for VmError {}
+
+// errors from `trace`, which can fail to write as well as to run
+#[derive(Debug)]
+pub enum TraceError {
+    Vm(VmError),
+    Io(io::Error),
+}
+
+impl fmt::Display for TraceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TraceError::Vm(error) => write!(f, "{error}"),
+            TraceError::Io(error) => write!(f, "could not write trace: {error}"),
+        }
+    }
+}
+
+impl error::Error for TraceError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            TraceError::Vm(error) => Some(error),
+            TraceError::Io(error) => Some(error),
+        }
+    }
+}
+
+impl From<VmError> for TraceError {
+    fn from(error: VmError) -> TraceError {
+        TraceError::Vm(error)
+    }
+}
+
+impl From<io::Error> for TraceError {
+    fn from(error: io::Error) -> TraceError {
+        TraceError::Io(error)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Vm<'a> {
+    program: &'a [Instruction],
+    // the address of the next instruction
+    counter: usize,
+    stack: Vec<i64>,
+    // return addresses for `ret`
+    calls: Vec<usize>,
+    output: Vec<i64>,
+    steps: usize,
+    step_limit: Option<usize>,
+    halted: bool,
+}
+
+impl<'a> Vm<'a> {
+    pub fn new(program: &'a Program) -> Vm<'a> {
+        Vm {
+            program: program.instructions(),
+            counter: 0,
+            stack: Vec::new(),
+            calls: Vec::new(),
+            output: Vec::new(),
+            steps: 0,
+            step_limit: None,
+            halted: false,
+        }
+    }
+
+    // stop with an error after `limit` instructions, rather than looping
+    // forever
+    pub fn with_step_limit(self, limit: usize) -> Vm<'a> {
+        Vm {
+            step_limit: Some(limit),
+            ..self
+        }
+    }
+
+    pub fn stack(&self) -> &[i64] {
+        &self.stack
+    }
+
+    pub fn output(&self) -> &[i64] {
+        &self.output
+    }
+
+    // the address of the next instruction to run
+    pub fn counter(&self) -> usize {
+        self.counter
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    // true after `halt`, or running off the end of the program
+    pub fn is_halted(&self) -> bool {
+        self.halted || self.counter >= self.program.len()
+    }
+
+    fn pop(&mut self) -> Result<i64, VmErrorKind> {
+        self.stack.pop().ok_or(VmErrorKind::StackUnderflow)
+    }
+
+    // the top two values, with the top one second, so `push 7, push 2, sub`
+    // is 7 - 2
+    fn pop_pair(&mut self) -> Result<(i64, i64), VmErrorKind> {
+        let b = self.pop()?;
+        let a = self.pop()?;
+
+        Ok((a, b))
+    }
+
+    fn peek(&self, depth: usize) -> Result<i64, VmErrorKind> {
+        self.stack
+            .len()
+            .checked_sub(depth + 1)
+            .map(|i| self.stack[i])
+            .ok_or(VmErrorKind::StackUnderflow)
+    }
+
+    fn arithmetic(&mut self, op: fn(i64, i64) -> Option<i64>) -> Result<(), VmErrorKind> {
+        let (a, b) = self.pop_pair()?;
+
+        self.stack.push(op(a, b).ok_or(VmErrorKind::Overflow)?);
+
+        Ok(())
+    }
+
+    fn divide(&mut self, op: fn(i64, i64) -> Option<i64>) -> Result<(), VmErrorKind> {
+        match self.peek(0)? {
+            0 => Err(VmErrorKind::DivisionByZero),
+            _ => self.arithmetic(op),
+        }
+    }
+
+    fn compare(&mut self, op: fn(&i64, &i64) -> bool) -> Result<(), VmErrorKind> {
+        let (a, b) = self.pop_pair()?;
+
+        self.stack.push(op(&a, &b) as i64);
+
+        Ok(())
+    }
+
+    // jumping to the end of the program is allowed, and halts it
+    fn jump(&mut self, target: usize) -> Result<(), VmErrorKind> {
+        if target > self.program.len() {
+            return Err(VmErrorKind::BadJump(target));
+        }
+
+        self.counter = target;
+
+        Ok(())
+    }
+
+    fn execute(&mut self, instruction: Instruction) -> Result<(), VmErrorKind> {
+        match instruction {
+            Instruction::Push(value) => self.stack.push(value),
+            Instruction::Pop => {
+                self.pop()?;
+            }
+            Instruction::Dup => self.stack.push(self.peek(0)?),
+            Instruction::Over => self.stack.push(self.peek(1)?),
+            Instruction::Swap => {
+                let (a, b) = self.pop_pair()?;
+
+                self.stack.extend([b, a]);
+            }
+            Instruction::Add => self.arithmetic(i64::checked_add)?,
+            Instruction::Sub => self.arithmetic(i64::checked_sub)?,
+            Instruction::Mul => self.arithmetic(i64::checked_mul)?,
+            Instruction::Div => self.divide(i64::checked_div)?,
+            Instruction::Rem => self.divide(i64::checked_rem)?,
+            Instruction::Eq => self.compare(i64::eq)?,
+            Instruction::Lt => self.compare(i64::lt)?,
+            Instruction::Gt => self.compare(i64::gt)?,
+            Instruction::Jump(target) => self.jump(target)?,
+            Instruction::JumpIfZero(target) => {
+                if self.pop()? == 0 {
+                    self.jump(target)?;
+                }
+            }
+            Instruction::JumpIfNotZero(target) => {
+                if self.pop()? != 0 {
+                    self.jump(target)?;
+                }
+            }
+            Instruction::Call(target) => {
+                let back = self.counter;
+
+                self.jump(target)?;
+                self.calls.push(back);
+            }
+            Instruction::Ret => {
+                let back = self.calls.pop().ok_or(VmErrorKind::ReturnWithoutCall)?;
+
+                self.counter = back;
+            }
+            Instruction::Print => {
+                let value = self.pop()?;
+
+                self.output.push(value);
+            }
+            Instruction::Halt => self.halted = true,
+        }
+
+        Ok(())
+    }
+
+    // run the next instruction, returning it, or None if the program has
+    // halted. If it fails, the VM is left as it was before the instruction,
+    // except for anything the instruction popped
+    pub fn step(&mut self) -> Result<Option<Instruction>, VmError> {
+        if self.is_halted() {
+            return Ok(None);
+        }
+
+        let address = self.counter;
+        let error = |kind| VmError { address, kind };
+
+        if let Some(limit) = self.step_limit.filter(|limit| self.steps >= *limit) {
+            return Err(error(VmErrorKind::StepLimit(limit)));
+        }
+
+        let instruction = self.program[address];
+
+        self.counter += 1;
+        self.steps += 1;
+        self.execute(instruction).map_err(|kind| {
+            self.counter = address;
+            error(kind)
+        })?;
+
+        Ok(Some(instruction))
+    }
+
+    pub fn run(&mut self) -> Result<(), VmError> {
+        while self.step()?.is_some() {}
+
+        Ok(())
+    }
+
+    // run, writing each instruction with its address and the stack after it
+    pub fn trace(&mut self, mut out: impl Write) -> Result<(), TraceError> {
+        loop {
+            let address = self.counter;
+
+            match self.step()? {
+                Some(instruction) => writeln!(
+                    out,
+                    "{address:04}  {:<10} {:?}",
+                    instruction.to_string(),
+                    self.stack
+                )?,
+                None => return Ok(()),
+            }
+        }
+    }
+}
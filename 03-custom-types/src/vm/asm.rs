@@ -0,0 +1,256 @@
+// The assembly language for `Vm` programs: one instruction per line, with
+// `;` starting a comment, and labels ending in `:` to jump to:
+//
+//     ; count down from 3
+//         push 3
+//     loop:
+//         dup
+//         print
+//         push 1
+//         sub
+//         dup
+//         jnz loop
+//
+// Jumps and calls take a label or an address. Labels can't start with a
+// digit, so that they can't be mistaken for addresses.
+//
+// Assembling is two passes over the source: the first finds the address of
+// every label, so that the second can resolve jumps to labels further down.
+// Displaying a `Program` goes the other way, naming each address that's
+// jumped to `L<address>`, so what it prints assembles back into the same
+// program.
+use std::collections::{BTreeSet, HashMap};
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+use super::{Instruction, Program};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssembleErrorKind {
+    UnknownInstruction(String),
+    // the instruction that's missing its operand
+    MissingOperand(String),
+    UnexpectedOperand(String),
+    InvalidOperand(String),
+    InvalidLabel(String),
+    UndefinedLabel(String),
+    DuplicateLabel(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssembleError {
+    // counting from 1
+    pub line: usize,
+    pub kind: AssembleErrorKind,
+}
+
+impl fmt::Display for AssembleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+
+        match &self.kind {
+            AssembleErrorKind::UnknownInstruction(name) => {
+                write!(f, "unknown instruction {name:?}")
+            }
+            AssembleErrorKind::MissingOperand(name) => write!(f, "{name} needs an operand"),
+            AssembleErrorKind::UnexpectedOperand(operand) => {
+                write!(f, "unexpected operand {operand:?}")
+            }
+            AssembleErrorKind::InvalidOperand(operand) => write!(f, "invalid operand {operand:?}"),
+            AssembleErrorKind::InvalidLabel(label) => write!(f, "invalid label {label:?}"),
+            AssembleErrorKind::UndefinedLabel(label) => write!(f, "undefined label {label:?}"),
+            AssembleErrorKind::DuplicateLabel(label) => {
+                write!(f, "label {label:?} is already defined")
+            }
+        }
+    }
+}
+
+impl error::Error for AssembleError {}
+
+// a line with its comment and labels removed
+struct Line<'a> {
+    number: usize,
+    name: &'a str,
+    operand: Option<&'a str>,
+}
+
+fn is_label(label: &str) -> bool {
+    label
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_')
+        && label.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+// the first pass: the address of each label, and the lines with instructions
+fn scan(source: &str) -> Result<(HashMap<&str, usize>, Vec<Line<'_>>), AssembleError> {
+    let mut labels = HashMap::new();
+    let mut lines = Vec::new();
+
+    for (i, line) in source.lines().enumerate() {
+        let error = |kind| AssembleError { line: i + 1, kind };
+        let mut rest = line.split(';').next().unwrap_or_default().trim();
+
+        while let Some((label, after)) = rest.split_once(':') {
+            let label = label.trim();
+
+            if !is_label(label) {
+                return Err(error(AssembleErrorKind::InvalidLabel(label.to_string())));
+            }
+
+            if labels.insert(label, lines.len()).is_some() {
+                return Err(error(AssembleErrorKind::DuplicateLabel(label.to_string())));
+            }
+
+            rest = after.trim();
+        }
+
+        let mut words = rest.split_whitespace();
+
+        if let Some(name) = words.next() {
+            let operand = words.next();
+
+            if let Some(extra) = words.next() {
+                return Err(error(AssembleErrorKind::UnexpectedOperand(
+                    extra.to_string(),
+                )));
+            }
+
+            lines.push(Line {
+                number: i + 1,
+                name,
+                operand,
+            });
+        }
+    }
+
+    Ok((labels, lines))
+}
+
+// the second pass: each line as an instruction
+fn assemble_line(
+    line: &Line<'_>,
+    labels: &HashMap<&str, usize>,
+) -> Result<Instruction, AssembleError> {
+    let error = |kind| AssembleError {
+        line: line.number,
+        kind,
+    };
+    let operand = || {
+        line.operand
+            .ok_or_else(|| error(AssembleErrorKind::MissingOperand(line.name.to_string())))
+    };
+    let target = || -> Result<usize, AssembleError> {
+        let operand = operand()?;
+
+        match operand.parse() {
+            Ok(address) => Ok(address),
+            Err(_) if is_label(operand) => labels
+                .get(operand)
+                .copied()
+                .ok_or_else(|| error(AssembleErrorKind::UndefinedLabel(operand.to_string()))),
+            Err(_) => Err(error(AssembleErrorKind::InvalidOperand(
+                operand.to_string(),
+            ))),
+        }
+    };
+
+    let instruction = match line.name {
+        "push" => {
+            let operand = operand()?;
+            let value = operand
+                .parse()
+                .map_err(|_| error(AssembleErrorKind::InvalidOperand(operand.to_string())))?;
+
+            return Ok(Instruction::Push(value));
+        }
+        "jmp" => return Ok(Instruction::Jump(target()?)),
+        "jz" => return Ok(Instruction::JumpIfZero(target()?)),
+        "jnz" => return Ok(Instruction::JumpIfNotZero(target()?)),
+        "call" => return Ok(Instruction::Call(target()?)),
+        "pop" => Instruction::Pop,
+        "dup" => Instruction::Dup,
+        "over" => Instruction::Over,
+        "swap" => Instruction::Swap,
+        "add" => Instruction::Add,
+        "sub" => Instruction::Sub,
+        "mul" => Instruction::Mul,
+        "div" => Instruction::Div,
+        "rem" => Instruction::Rem,
+        "eq" => Instruction::Eq,
+        "lt" => Instruction::Lt,
+        "gt" => Instruction::Gt,
+        "ret" => Instruction::Ret,
+        "print" => Instruction::Print,
+        "halt" => Instruction::Halt,
+        name => {
+            return Err(error(AssembleErrorKind::UnknownInstruction(
+                name.to_string(),
+            )))
+        }
+    };
+
+    // only the instructions that returned above take an operand
+    match line.operand {
+        Some(operand) => Err(error(AssembleErrorKind::UnexpectedOperand(
+            operand.to_string(),
+        ))),
+        None => Ok(instruction),
+    }
+}
+
+pub fn assemble(source: &str) -> Result<Program, AssembleError> {
+    let (labels, lines) = scan(source)?;
+
+    lines
+        .iter()
+        .map(|line| assemble_line(line, &labels))
+        .collect::<Result<Vec<_>, _>>()
+        .map(Program::new)
+}
+
+impl FromStr for Program {
+    type Err = AssembleError;
+
+    fn from_str(s: &str) -> Result<Program, Self::Err> {
+        assemble(s)
+    }
+}
+
+// the disassembler
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let instructions = self.instructions();
+        // addresses past the end can't have a label, so are left as numbers
+        let targets: BTreeSet<usize> = instructions
+            .iter()
+            .filter_map(Instruction::target)
+            .filter(|target| *target <= instructions.len())
+            .collect();
+        let mut lines = Vec::new();
+
+        for (address, instruction) in instructions.iter().enumerate() {
+            if targets.contains(&address) {
+                lines.push(format!("L{address}:"));
+            }
+
+            let line = match instruction.target() {
+                Some(target) if targets.contains(&target) => {
+                    format!("    {} L{target}", instruction.name())
+                }
+                _ => format!("    {instruction}"),
+            };
+
+            lines.push(line);
+        }
+
+        // a jump to the end of the program
+        if targets.contains(&instructions.len()) {
+            lines.push(format!("L{}:", instructions.len()));
+        }
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
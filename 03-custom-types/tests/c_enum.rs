@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod c_enum_tests {
+    use custom_types::c_enum;
+    use custom_types::c_enum::{InvalidDiscriminant, ParseVariantError};
+    use std::collections::HashSet;
+
+    c_enum! {
+        // attributes are passed on to the enum
+        #[derive(PartialOrd, Ord)]
+        pub enum Color {
+            Red = 1,
+            // follows on from Red
+            Green,
+            Blue = 10,
+        }
+    }
+
+    c_enum! {
+        #[repr(u8)]
+        enum Opcode {
+            Nop,
+            Load = 0x20,
+            Halt = 0xff
+        }
+    }
+
+    c_enum! {
+        enum Wide {
+            Small = -1,
+            Large = 300,
+        }
+    }
+
+    #[test]
+    fn try_from_integers() {
+        assert_eq!(Color::try_from(1_i32), Ok(Color::Red));
+        assert_eq!(Color::try_from(2_u8), Ok(Color::Green));
+        assert_eq!(Color::try_from(10_i32), Ok(Color::Blue));
+        assert_eq!(
+            Color::try_from(3_i32),
+            Err(InvalidDiscriminant {
+                enum_name: "Color",
+                value: 3
+            })
+        );
+        assert_eq!(Opcode::try_from(0xff_u8), Ok(Opcode::Halt));
+        assert_eq!(Opcode::try_from(0_u8), Ok(Opcode::Nop));
+    }
+
+    c_enum! {
+        #[repr(u64)]
+        enum Big {
+            A = 0,
+            B = u64::MAX,
+        }
+    }
+
+    #[test]
+    fn integers_dont_wrap() {
+        // 300 as u8 is 44, and -1 as u8 is 255
+        assert_eq!(Wide::try_from(300_i32), Ok(Wide::Large));
+        assert_eq!(Wide::try_from(-1_i32), Ok(Wide::Small));
+        assert!(Wide::try_from(44_u8).is_err());
+        assert!(Wide::try_from(255_u8).is_err());
+        // u64::MAX as i64 is -1
+        assert!(Big::try_from(-1_i32).is_err());
+        assert_eq!(Big::try_from(0_u8), Ok(Big::A));
+        assert_eq!(Big::B as u64, u64::MAX);
+        assert_eq!(
+            Wide::try_from(255_u8).unwrap_err().to_string(),
+            "255 is not a discriminant of Wide"
+        );
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for color in Color::iter() {
+            assert_eq!(color.to_string().parse(), Ok(color));
+        }
+
+        assert_eq!(Opcode::Load.name(), "Load");
+        assert_eq!(format!("[{:<6}]", Color::Red), "[Red   ]");
+        assert_eq!(
+            "red".parse::<Color>(),
+            Err(ParseVariantError {
+                enum_name: "Color",
+                name: "red".to_string()
+            })
+        );
+        assert_eq!(
+            "".parse::<Color>().unwrap_err().to_string(),
+            "\"\" is not a variant of Color"
+        );
+    }
+
+    #[test]
+    fn all_variants_in_order() {
+        assert_eq!(Color::ALL, [Color::Red, Color::Green, Color::Blue]);
+        assert_eq!(Opcode::ALL.len(), 3);
+        assert_eq!(
+            Opcode::iter().map(|op| op as u8).collect::<Vec<_>>(),
+            [0, 0x20, 0xff]
+        );
+
+        // ALL is a const, so it can size other arrays
+        const NAMES: [&str; Color::ALL.len()] = ["red", "green", "blue"];
+
+        assert_eq!(NAMES.len(), 3);
+    }
+
+    #[test]
+    fn derives() {
+        let set: HashSet<Color> = Color::iter().chain(Color::iter()).collect();
+        let mut colors = vec![Color::Blue, Color::Red, Color::Green];
+
+        colors.sort();
+
+        assert_eq!(set.len(), 3);
+        assert_eq!(colors, Color::ALL);
+        assert_eq!(format!("{:?}", Color::Green), "Green");
+    }
+}
@@ -0,0 +1,150 @@
+#[cfg(test)]
+mod canvas_tests {
+    use custom_types::geometry::canvas::Canvas;
+    use custom_types::geometry::{Circle, Point, Polygon, Rect};
+
+    #[test]
+    fn lines_in_every_direction() {
+        let mut canvas = Canvas::new(5, 5);
+
+        canvas.line(Point::new(0, 0), Point::new(4, 2), '\\');
+        canvas.line(Point::new(0, 4), Point::new(4, 4), '_');
+        canvas.line(Point::new(4, 0), Point::new(4, 0), '*');
+
+        assert_eq!(canvas.to_string(), "\\   *\n \\\\\n   \\\\\n\n_____");
+
+        // drawing a line backwards fills the same cells
+        let mut forwards = Canvas::new(7, 4);
+        let mut backwards = Canvas::new(7, 4);
+
+        forwards.line(Point::new(1, 3), Point::new(6, 0), '#');
+        backwards.line(Point::new(6, 0), Point::new(1, 3), '#');
+
+        assert_eq!(forwards, backwards);
+    }
+
+    #[test]
+    fn steep_lines_have_a_cell_per_row() {
+        let mut canvas = Canvas::new(3, 6);
+
+        canvas.line(Point::new(0, 0), Point::new(2, 5), '|');
+
+        for row in 0..6 {
+            let filled = (0..3).filter(|&column| canvas.get(column, row) == Some('|'));
+
+            assert_eq!(filled.count(), 1, "row {row}");
+        }
+    }
+
+    #[test]
+    fn rects_at_their_position() {
+        let mut canvas = Canvas::new(6, 4);
+
+        canvas.draw(&Rect::new(Point::new(4, 3), Point::new(1, 1)), '#');
+
+        assert_eq!(canvas.to_string(), "\n ####\n #  #\n ####");
+        assert_eq!(canvas.get(1, 1), Some('#'));
+        assert_eq!(canvas.get(2, 2), Some(' '));
+        assert_eq!(canvas.get(6, 0), None);
+    }
+
+    #[test]
+    fn midpoint_circles() {
+        let mut canvas = Canvas::new(7, 7).with_background('.');
+
+        canvas.draw(&Circle::new(Point::new(3, 3), 3), 'o');
+
+        assert_eq!(
+            canvas.to_string(),
+            ["..ooo..", ".o...o.", "o.....o", "o.....o", "o.....o", ".o...o.", "..ooo.."]
+                .join("\n")
+        );
+
+        canvas.clear();
+        canvas.draw(&Circle::new(Point::new(3, 3), 0), 'o');
+
+        assert_eq!(canvas.get(3, 3), Some('o'));
+        assert_eq!(canvas.to_string().matches('o').count(), 1);
+    }
+
+    #[test]
+    fn scale_origin_and_clipping() {
+        // a unit square, 4 cells per unit, starting a unit to the left of the
+        // canvas, so only its right half shows
+        let mut canvas = Canvas::new(4, 5)
+            .with_scale(4.0)
+            .with_origin(Point::new(1.5, 0.0));
+
+        assert_eq!(canvas.cell(Point::new(2.0, 1.0)), (2, 4));
+        assert_eq!(canvas.cell(Point::new(0.0, 0.0)), (-6, 0));
+
+        canvas.draw(&Rect::square(Point::new(1.0, 0.0), 1.0), '#');
+
+        assert_eq!(canvas.to_string(), "###\n  #\n  #\n  #\n###");
+    }
+
+    #[test]
+    fn far_off_shapes() {
+        // only the part of a shape that's on the canvas is drawn, however big
+        // the shape is
+        let mut canvas = Canvas::new(10, 5);
+
+        canvas.line(Point::new(0.0, 0.0), Point::new(3e9, 0.0), '#');
+        canvas.line(Point::new(-1e300, 2.0), Point::new(1e300, 2.0), '=');
+        canvas.draw(&Circle::new(Point::new(0.0, 0.0), 1e300), '*');
+
+        assert_eq!(canvas.to_string(), "##########\n\n==========\n\n");
+        assert_eq!(canvas.cell(Point::new(-1e300, 1e300)), (i64::MIN, i64::MAX));
+
+        canvas.clear();
+        canvas.draw(&Rect::new(Point::new(0, 0), Point::new(i32::MAX, 2)), '#');
+
+        assert_eq!(canvas.to_string(), "##########\n#\n##########\n\n");
+
+        // too big to walk round, but it crosses the canvas along row 2
+        canvas.clear();
+        canvas.draw(&Circle::new(Point::new(4.0, 1e7 + 2.0), 1e7), 'o');
+
+        assert_eq!(canvas.to_string(), "\n\noooooooooo\n\n");
+    }
+
+    #[test]
+    fn debug_sketches() {
+        let rect = Rect::new(Point::new(1, 1), Point::new(2, 2));
+
+        assert_eq!(
+            format!("{rect:?}"),
+            "Rect { min: Point { x: 1, y: 1 }, max: Point { x: 2, y: 2 } }"
+        );
+        // 2 cells per unit
+        assert!(format!("{rect:#?}").ends_with("}\n+\n\n  ###\n  # #\n  ###"));
+
+        // shrunk to fit in 40 cells
+        let circle = Circle::new(Point::new(0.0, 0.0), 1e6);
+        let sketch = Canvas::sketch_within(&circle, 2.0, 40);
+
+        assert!(format!("{circle:#?}").ends_with(&sketch.to_string()));
+        assert_eq!((sketch.width(), sketch.height()), (40, 40));
+    }
+
+    #[test]
+    fn polygons_points_and_sketches() {
+        let mut canvas = Canvas::new(5, 3);
+
+        canvas.draw(
+            &Polygon::new([Point::new(0, 2), Point::new(2, 0), Point::new(4, 2)]),
+            '*',
+        );
+        canvas.draw(&Point::new(2, 1), '.');
+
+        assert_eq!(canvas.to_string(), "  *\n *.*\n*****");
+
+        // a sketch includes the origin, so moving a shape moves it on the canvas
+        let near = Canvas::sketch(&Rect::new(Point::new(1, 1), Point::new(2, 2)), 1.0);
+        let far = Canvas::sketch(&Rect::new(Point::new(3, 1), Point::new(4, 2)), 1.0);
+
+        assert_eq!(near.to_string(), "+\n ##\n ##");
+        assert_eq!(far.to_string(), "+\n   ##\n   ##");
+        assert_eq!((far.width(), far.height()), (5, 3));
+    }
+}
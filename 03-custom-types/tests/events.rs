@@ -0,0 +1,222 @@
+#[cfg(test)]
+mod events_tests {
+    use custom_types::events::{
+        Event, EventBus, EventKind, ParseEventError, ScriptError, ScrollDir,
+    };
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    // a bus that records every event it handles
+    fn recording_bus() -> (EventBus, Rc<RefCell<Vec<Event>>>) {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let mut bus = EventBus::new();
+
+        bus.on_any({
+            let seen = Rc::clone(&seen);
+
+            move |event, _| seen.borrow_mut().push(*event)
+        });
+
+        (bus, seen)
+    }
+
+    #[test]
+    fn handlers_only_see_their_kind() {
+        let keys = Rc::new(RefCell::new(String::new()));
+        let mut bus = EventBus::new();
+
+        bus.on(EventKind::KeyPress, {
+            let keys = Rc::clone(&keys);
+
+            move |event, _| {
+                if let Event::KeyPress(key) = event {
+                    keys.borrow_mut().push(*key);
+                }
+            }
+        });
+
+        bus.push(Event::KeyPress('h'));
+        bus.push(Event::MouseClick(1, 2));
+        bus.push(Event::Scroll(ScrollDir::Up));
+        bus.push(Event::KeyPress('i'));
+
+        assert_eq!(bus.run(), 4);
+        assert_eq!(*keys.borrow(), "hi");
+        assert_eq!(bus.dispatch(), None);
+    }
+
+    #[test]
+    fn handlers_run_in_registration_order() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let mut bus = EventBus::new();
+
+        for name in ["first", "second", "third"] {
+            let calls = Rc::clone(&calls);
+
+            bus.on(EventKind::MouseClick, move |_, _| {
+                calls.borrow_mut().push(name)
+            });
+        }
+
+        bus.push(Event::MouseClick(0, 0));
+
+        assert_eq!(bus.dispatch(), Some(Event::MouseClick(0, 0)));
+        assert_eq!(*calls.borrow(), ["first", "second", "third"]);
+    }
+
+    #[test]
+    fn unregistering() {
+        let (mut bus, seen) = recording_bus();
+        let count = Rc::new(RefCell::new(0));
+        let id = bus.on(EventKind::KeyPress, {
+            let count = Rc::clone(&count);
+
+            move |_, _| *count.borrow_mut() += 1
+        });
+
+        bus.push(Event::KeyPress('a'));
+        bus.run();
+
+        assert!(bus.off(id));
+        assert!(!bus.off(id));
+
+        bus.push(Event::KeyPress('b'));
+        bus.run();
+
+        assert_eq!(*count.borrow(), 1);
+        assert_eq!(seen.borrow().len(), 2);
+    }
+
+    #[test]
+    fn follow_ups_go_to_the_back_of_the_queue() {
+        let (mut bus, seen) = recording_bus();
+
+        bus.on(EventKind::KeyPress, |event, emitter| {
+            if let Event::KeyPress('j') = event {
+                emitter.emit(Event::Scroll(ScrollDir::Down));
+            }
+        });
+
+        bus.push(Event::KeyPress('j'));
+        bus.push(Event::MouseClick(1, 1));
+
+        assert_eq!(bus.dispatch(), Some(Event::KeyPress('j')));
+        assert_eq!(
+            bus.pending().copied().collect::<Vec<_>>(),
+            [Event::MouseClick(1, 1), Event::Scroll(ScrollDir::Down)]
+        );
+        assert_eq!(bus.run(), 2);
+        assert_eq!(
+            *seen.borrow(),
+            [
+                Event::KeyPress('j'),
+                Event::MouseClick(1, 1),
+                Event::Scroll(ScrollDir::Down),
+            ]
+        );
+    }
+
+    #[test]
+    fn events_parse_from_their_display() {
+        let events = [
+            Event::MouseClick(-3, 40),
+            Event::KeyPress('é'),
+            Event::Scroll(ScrollDir::Left),
+        ];
+
+        for event in events {
+            assert_eq!(event.to_string().parse(), Ok(event));
+        }
+
+        assert_eq!("  ".parse::<Event>(), Err(ParseEventError::Empty));
+        assert_eq!(
+            "drag 1 2".parse::<Event>(),
+            Err(ParseEventError::UnknownEvent("drag".to_string()))
+        );
+
+        for invalid in ["click 1", "click 1 x", "key", "key ab", "scroll in"] {
+            assert_eq!(
+                invalid.parse::<Event>(),
+                Err(ParseEventError::InvalidArguments(invalid.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn every_event_round_trips() {
+        let clicks = [(0, 0), (i32::MIN, i32::MAX)].map(|(x, y)| Event::MouseClick(x, y));
+        // whitespace and control keys are escaped, and everything else is
+        // written as it is
+        let keys =
+            [' ', '\t', '\n', '\u{3000}', '\0', '\\', '#', '{', 'é', '🦀'].map(Event::KeyPress);
+        let scrolls = [
+            ScrollDir::Up,
+            ScrollDir::Down,
+            ScrollDir::Left,
+            ScrollDir::Right,
+        ]
+        .map(Event::Scroll);
+
+        for event in clicks.into_iter().chain(keys).chain(scrolls) {
+            assert_eq!(event.to_string().parse(), Ok(event), "{event}");
+        }
+
+        for key in char::MIN..=char::MAX {
+            assert_eq!(
+                Event::KeyPress(key).to_string().parse(),
+                Ok(Event::KeyPress(key))
+            );
+        }
+
+        assert_eq!(Event::KeyPress(' ').to_string(), "key \\u{20}");
+        assert_eq!(Event::KeyPress('\\').to_string(), "key \\");
+        assert_eq!("key \\u{41}".parse(), Ok(Event::KeyPress('A')));
+
+        for invalid in ["key \\u{110000}", "key \\u{d800}", "key \\u{}", "key \\u20"] {
+            assert_eq!(
+                invalid.parse::<Event>(),
+                Err(ParseEventError::InvalidArguments(invalid.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn scripts() {
+        let (mut bus, seen) = recording_bus();
+
+        bus.on(EventKind::KeyPress, |_, emitter| {
+            emitter.emit(Event::Scroll(ScrollDir::Down))
+        });
+
+        let script = "# a comment\n\nkey j\n  click 5 6  \nkey k\n";
+
+        assert_eq!(bus.run_script(script.as_bytes()).unwrap(), 5);
+        assert_eq!(
+            *seen.borrow(),
+            [
+                Event::KeyPress('j'),
+                Event::Scroll(ScrollDir::Down),
+                Event::MouseClick(5, 6),
+                Event::KeyPress('k'),
+                Event::Scroll(ScrollDir::Down),
+            ]
+        );
+
+        // the lines before an invalid one have already run, and the error
+        // says how many events they handled
+        match bus.run_script("click 1 1\n\nkey\nkey z\n".as_bytes()) {
+            Err(ScriptError::Parse {
+                line,
+                error,
+                handled,
+            }) => {
+                assert_eq!(line, 3);
+                assert_eq!(error, ParseEventError::InvalidArguments("key".to_string()));
+                assert_eq!(handled, 1);
+            }
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+
+        assert_eq!(seen.borrow().last(), Some(&Event::MouseClick(1, 1)));
+    }
+}
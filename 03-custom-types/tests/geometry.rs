@@ -0,0 +1,167 @@
+#[cfg(test)]
+mod geometry_tests {
+    use custom_types::geometry::{Circle, HasArea, Point, Polygon, Rect, Vector};
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{a} != {b}");
+    }
+
+    #[test]
+    fn points_and_vectors() {
+        let (a, b) = (Point::new(1, 2), Point::new(4, 6));
+        let v = b - a;
+
+        assert_eq!(v, Vector::new(3, 4));
+        assert_eq!(a + v, b);
+        assert_eq!(b - v, a);
+        assert_eq!(v.length(), 5.0);
+        assert_eq!(a.distance_to(&b), 5.0);
+        assert_eq!(-v * 2, Vector::new(-6, -8));
+        assert_eq!(v.dot(&Vector::new(1, 0)), 3);
+        // (1, 0) is clockwise from (0, 1)
+        assert_eq!(Vector::new(0, 1).cross(&Vector::new(1, 0)), -1);
+
+        let mut p = Point::origin();
+
+        p += Vector::new(0.5, -1.0);
+
+        assert_eq!(p, Point::new(0.5, -1.0));
+        assert_eq!(format!("{p:.2}"), "(0.50, -1.00)");
+    }
+
+    #[test]
+    fn rects_are_normalised() {
+        let rect = Rect::new(Point::new(3.0, 5.0), Point::new(1.0, 2.0));
+
+        assert_eq!(rect.min(), Point::new(1.0, 2.0));
+        assert_eq!(rect.max(), Point::new(3.0, 5.0));
+        assert_eq!(rect, Rect::new(Point::new(1.0, 5.0), Point::new(3.0, 2.0)));
+        assert_eq!(rect.area(), 6.0);
+        assert_eq!(rect.perimeter(), 10.0);
+        assert_eq!(rect.center(), Point::new(2.0, 3.5));
+
+        let negative = Rect::with_size(Point::new(0, 0), -4, 2);
+
+        assert_eq!(negative.width(), 4);
+        assert_eq!(negative.min(), Point::new(-4, 0));
+        assert_eq!(Rect::square(Point::new(1, 1), 2).area(), 4.0);
+    }
+
+    #[test]
+    fn rect_intersection_and_union() {
+        let a = Rect::new(Point::new(0, 0), Point::new(4, 4));
+        let b = Rect::new(Point::new(2, 3), Point::new(6, 5));
+        let far = Rect::new(Point::new(10, 10), Point::new(11, 11));
+
+        assert_eq!(
+            a.intersection(&b),
+            Some(Rect::new(Point::new(2, 3), Point::new(4, 4)))
+        );
+        assert_eq!(a.intersection(&far), None);
+        assert!(a.intersects(&b));
+        // touching edges count
+        assert!(a.intersects(&Rect::new(Point::new(4, 0), Point::new(5, 1))));
+        assert_eq!(a.union(&b), Rect::new(Point::new(0, 0), Point::new(6, 5)));
+
+        assert!(a.contains(&Point::new(4, 0)));
+        assert!(!a.contains(&Point::new(5, 0)));
+        assert!(a.union(&b).contains_rect(&b));
+        assert!(!a.contains_rect(&b));
+    }
+
+    #[test]
+    fn circles() {
+        let mut circle = Circle::new(Point::new(0, 0), -5);
+
+        assert_eq!(circle.radius(), 5);
+        assert_close(circle.area(), 25.0 * std::f64::consts::PI);
+        assert_close(circle.perimeter(), 10.0 * std::f64::consts::PI);
+        assert_eq!(
+            circle.bounding_box(),
+            Rect::new(Point::new(-5, -5), Point::new(5, 5))
+        );
+        assert!(circle.contains(&Point::new(3, 4)));
+        assert!(!circle.contains(&Point::new(4, 4)));
+        assert!(circle.intersects(&Circle::new(Point::new(10, 0), 5)));
+        assert!(!circle.intersects(&Circle::new(Point::new(10, 1), 5)));
+
+        circle.translate(Vector::new(1, 1));
+
+        assert_eq!(circle.center(), Point::new(1, 1));
+        assert_eq!(circle.to_string(), "Circle at (1, 1) with radius 5");
+    }
+
+    #[test]
+    fn polygons() {
+        // an L shape, going anticlockwise
+        let l_shape = Polygon::new([
+            Point::new(0, 0),
+            Point::new(4, 0),
+            Point::new(4, 1),
+            Point::new(1, 1),
+            Point::new(1, 3),
+            Point::new(0, 3),
+        ]);
+
+        assert_eq!(l_shape.area(), 6.0);
+        assert_eq!(l_shape.perimeter(), 14.0);
+        assert_eq!(
+            l_shape.bounding_box(),
+            Rect::new(Point::new(0, 0), Point::new(4, 3))
+        );
+        assert!(l_shape.contains(&Point::new(3, 0)));
+        assert!(l_shape.contains(&Point::new(0, 2)));
+        assert!(!l_shape.contains(&Point::new(2, 2)));
+
+        // the same shape clockwise has the same area
+        let reversed = Polygon::new(l_shape.vertices().iter().rev().copied());
+
+        assert_eq!(reversed.area(), 6.0);
+        assert_eq!(Polygon::<f64>::new([]).area(), 0.0);
+    }
+
+    #[test]
+    fn integer_shapes_dont_overflow() {
+        let wide = Rect::new(Point::new(i32::MIN, 0), Point::new(i32::MAX, 1));
+        let tiny = Rect::new(Point::new(-100_i8, -100), Point::new(100, 100));
+
+        assert_eq!(wide.area(), u32::MAX as f64);
+        assert_eq!(tiny.area(), 40_000.0);
+        assert_eq!(tiny.perimeter(), 800.0);
+
+        // 50,000² doesn't fit in an i32
+        let circle = Circle::new(Point::new(0, 0), 50_000);
+
+        assert!(circle.contains(&Point::new(0, 0)));
+        assert!(circle.contains(&Point::new(30_000, -40_000)));
+        assert!(!circle.contains(&Point::new(i32::MAX, i32::MIN)));
+        assert!(circle.intersects(&Circle::new(Point::new(100_000, 0), 50_000)));
+        assert!(!Circle::new(Point::new(i32::MIN, 0), 1).intersects(&circle));
+
+        let triangle = Polygon::new([
+            Point::new(-100_i8, -100),
+            Point::new(100, -100),
+            Point::new(0, 100),
+        ]);
+
+        assert_eq!(triangle.area(), 20_000.0);
+        assert_eq!(
+            Point::new(i8::MIN, 0).distance_to(&Point::new(i8::MAX, 0)),
+            255.0
+        );
+    }
+
+    #[test]
+    fn shapes_share_has_area() {
+        fn total_area(shapes: &[&dyn HasArea<Unit = i32>]) -> f64 {
+            shapes.iter().map(|shape| shape.area()).sum()
+        }
+
+        let rect = Rect::new(Point::new(0, 0), Point::new(2, 3));
+        let triangle = Polygon::new([Point::new(0, 0), Point::new(4, 0), Point::new(0, 3)]);
+
+        assert_eq!(total_area(&[&rect, &triangle]), 12.0);
+        assert_eq!(rect.bounding_box(), rect);
+        assert_eq!(rect.to_string(), "Rect (0, 0) to (2, 3)");
+    }
+}
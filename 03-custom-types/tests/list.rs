@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod list_tests {
+    use custom_types::list::List;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn prepend_head_and_tail() {
+        let list = List::new().prepend(3).prepend(4).prepend(5);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.head(), Some(&5));
+        assert_eq!(list.tail().and_then(List::head), Some(&4));
+        assert_eq!(list.tail().unwrap().len(), 2);
+
+        let empty: List<u32> = List::new();
+
+        assert!(empty.is_empty());
+        assert_eq!(empty.head(), None);
+        assert!(empty.tail().is_none());
+    }
+
+    #[test]
+    fn pop_front() {
+        let mut list: List<_> = (1..=3).collect();
+
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn reverse_and_append() {
+        let list: List<_> = (1..=3).collect();
+        let other: List<_> = (4..=5).collect();
+
+        assert_eq!(list.clone().reverse(), (1..=3).rev().collect());
+        assert_eq!(list.append(other), (1..=5).collect());
+        assert_eq!(List::<u8>::new().reverse(), List::new());
+    }
+
+    #[test]
+    fn iterators() {
+        let mut list: List<_> = vec![1, 2, 3].into_iter().collect();
+
+        for elem in list.iter_mut() {
+            *elem *= 10;
+        }
+
+        assert_eq!(list.iter().len(), 3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [10, 20, 30]);
+        assert_eq!((&list).into_iter().sum::<i32>(), 60);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), [10, 20, 30]);
+    }
+
+    #[test]
+    fn display_and_debug() {
+        let list: List<_> = [1.5, 2.25].into_iter().collect();
+
+        assert_eq!(list.to_string(), "1.5 2.25 Nil");
+        assert_eq!(format!("{list:.1}"), "1.5 2.2 Nil");
+        assert_eq!(format!("{list:?}"), "[1.5, 2.25]");
+        assert_eq!(List::<u8>::new().to_string(), "Nil");
+    }
+
+    #[test]
+    fn comparison_and_hashing() {
+        let a: List<_> = [1, 2, 3].into_iter().collect();
+        let b: List<_> = [1, 2, 4].into_iter().collect();
+        let prefix: List<_> = [1, 2].into_iter().collect();
+
+        assert_eq!(a, a.clone());
+        assert_ne!(a, b);
+        assert!(a < b);
+        assert!(prefix < a);
+        assert_eq!(a.cmp(&a.clone()), std::cmp::Ordering::Equal);
+        assert_eq!(hash_of(&a), hash_of(&a.clone()));
+        assert_ne!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn long_lists_do_not_overflow_the_stack() {
+        let list: List<u32> = (0..1_000_000).collect();
+
+        assert_eq!(list.len(), 1_000_000);
+        assert_eq!(list, list.clone());
+        assert!(list.to_string().ends_with("999999 Nil"));
+
+        drop(list);
+    }
+}
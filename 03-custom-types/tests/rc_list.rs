@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod rc_list_tests {
+    use custom_types::list::{self, rc::List};
+
+    #[test]
+    fn prepend_shares_the_tail() {
+        let shared: List<_> = [2, 1].into_iter().collect();
+
+        assert_eq!(shared.strong_count(), 1);
+
+        let a = shared.prepend(3);
+        let b = shared.prepend(4);
+
+        assert_eq!(shared.strong_count(), 3);
+        assert!(a.tail().unwrap().ptr_eq(&shared));
+        assert!(b.tail().unwrap().ptr_eq(&shared));
+        assert_eq!(a.len(), 3);
+        assert_eq!(a.iter().copied().collect::<Vec<_>>(), [3, 2, 1]);
+        assert_eq!(b.iter().copied().collect::<Vec<_>>(), [4, 2, 1]);
+
+        // nodes further down the list are only pointed at by the node in
+        // front of them
+        assert_eq!(shared.tail().unwrap().strong_count(), 1);
+
+        drop(a);
+        assert_eq!(shared.strong_count(), 2);
+
+        drop(b);
+        assert_eq!(shared.strong_count(), 1);
+    }
+
+    #[test]
+    fn tails_outlive_their_lists() {
+        let list: List<_> = ["a", "b", "c"].into_iter().collect();
+        let tail = list.tail().cloned().unwrap();
+
+        assert_eq!(tail.strong_count(), 2);
+
+        drop(list);
+
+        assert_eq!(tail.strong_count(), 1);
+        assert_eq!(tail.to_string(), "b c Nil");
+    }
+
+    #[test]
+    fn clone_is_shallow() {
+        // `String` values would be copied by a deep clone
+        let list: List<_> = ["x".to_string()].into_iter().collect();
+        let clone = list.clone();
+
+        assert!(clone.ptr_eq(&list));
+        assert_eq!(list.strong_count(), 2);
+        assert!(std::ptr::eq(list.head().unwrap(), clone.head().unwrap()));
+    }
+
+    #[test]
+    fn empty_lists() {
+        let empty: List<u8> = List::new();
+
+        assert!(empty.is_empty());
+        assert_eq!(empty.strong_count(), 0);
+        assert_eq!(empty.head(), None);
+        assert!(empty.tail().is_none());
+        assert!(empty.ptr_eq(&List::default()));
+        assert_eq!(format!("{empty} {empty:?}"), "Nil []");
+    }
+
+    #[test]
+    fn equality_and_ordering() {
+        let a: List<_> = (1..=3).collect();
+        let b: List<_> = (1..=3).collect();
+
+        assert!(!a.ptr_eq(&b));
+        assert_eq!(a, b);
+        assert!(a < a.prepend(0).prepend(9));
+        assert_eq!(List::from((1..=3).collect::<list::List<_>>()), a);
+    }
+
+    #[test]
+    fn long_lists_do_not_overflow_the_stack() {
+        let list: List<u32> = (0..1_000_000).collect();
+        let other = list.prepend(42);
+
+        assert_eq!(other.len(), 1_000_001);
+
+        // dropping `list` stops at the first node that `other` still shares
+        drop(list);
+        assert_eq!(other.tail().unwrap().strong_count(), 1);
+
+        drop(other);
+    }
+}
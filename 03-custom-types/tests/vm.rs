@@ -0,0 +1,237 @@
+#[cfg(test)]
+mod vm_tests {
+    use custom_types::vm::asm::{assemble, AssembleError, AssembleErrorKind};
+    use custom_types::vm::{Instruction, Program, TraceError, Vm, VmError, VmErrorKind};
+
+    fn run(source: &str) -> Result<Vec<i64>, VmError> {
+        let program = assemble(source).unwrap();
+        let mut vm = Vm::new(&program).with_step_limit(10_000);
+
+        vm.run()?;
+
+        Ok(vm.output().to_vec())
+    }
+
+    #[test]
+    fn arithmetic_and_comparisons() {
+        assert_eq!(run("push 7\npush 2\nsub\nprint"), Ok(vec![5]));
+        assert_eq!(run("push 7\npush 2\ndiv\nprint"), Ok(vec![3]));
+        assert_eq!(run("push -7\npush 2\nrem\nprint"), Ok(vec![-1]));
+        assert_eq!(run("push 3\npush 4\nmul\nprint"), Ok(vec![12]));
+        assert_eq!(
+            run("push 1\npush 2\nlt\nprint\npush 1\npush 2\ngt\nprint\npush 2\ndup\neq\nprint"),
+            Ok(vec![1, 0, 1])
+        );
+        assert_eq!(
+            run("push 1\npush 2\nover\nprint\nswap\nprint\nprint"),
+            Ok(vec![1, 1, 2])
+        );
+    }
+
+    #[test]
+    fn jumps_and_calls() {
+        // sums 1 to 10 in a loop, then doubles it in a subroutine
+        let source = "
+                push 0          ; sum
+                push 10         ; n
+            loop:
+                dup
+                jz done
+                swap
+                over
+                add
+                swap
+                push 1
+                sub
+                jmp loop
+            done: pop
+                call double
+                print
+                halt
+                push 999        ; never reached
+                print
+            double:
+                dup
+                add
+                ret
+        ";
+
+        assert_eq!(run(source), Ok(vec![110]));
+        assert_eq!(run("push 0\njnz 3\npush 1\nprint"), Ok(vec![1]));
+        assert_eq!(run("push 2\njnz 3\npush 1\nprint"), Err(underflow(3)));
+    }
+
+    fn underflow(address: usize) -> VmError {
+        VmError {
+            address,
+            kind: VmErrorKind::StackUnderflow,
+        }
+    }
+
+    #[test]
+    fn errors_say_where() {
+        let error = |address, kind| Err(VmError { address, kind });
+
+        assert_eq!(run("pop"), Err(underflow(0)));
+        assert_eq!(run("push 1\nadd"), Err(underflow(1)));
+        assert_eq!(
+            run("push 1\npush 0\nrem"),
+            error(2, VmErrorKind::DivisionByZero)
+        );
+        assert_eq!(
+            run(&format!("push {}\npush 1\nadd", i64::MAX)),
+            error(2, VmErrorKind::Overflow)
+        );
+        assert_eq!(run("jmp 3\nhalt"), error(0, VmErrorKind::BadJump(3)));
+        assert_eq!(run("call 5"), error(0, VmErrorKind::BadJump(5)));
+        assert_eq!(run("ret"), error(0, VmErrorKind::ReturnWithoutCall));
+        assert_eq!(
+            run("top: jmp top"),
+            error(0, VmErrorKind::StepLimit(10_000))
+        );
+        // jumping to the end of the program halts it
+        assert_eq!(run("push 1\njmp 3\nprint"), Ok(vec![]));
+
+        assert_eq!(
+            VmError {
+                address: 4,
+                kind: VmErrorKind::BadJump(9)
+            }
+            .to_string(),
+            "at address 4: jump to 9, outside the program"
+        );
+    }
+
+    #[test]
+    fn stepping() {
+        let program = Program::new(vec![
+            Instruction::Push(4),
+            Instruction::Dup,
+            Instruction::Mul,
+            Instruction::Halt,
+            Instruction::Print,
+        ]);
+        let mut vm = Vm::new(&program);
+
+        assert_eq!(vm.step(), Ok(Some(Instruction::Push(4))));
+        assert_eq!(vm.step(), Ok(Some(Instruction::Dup)));
+        assert_eq!(vm.stack(), [4, 4]);
+        assert_eq!(vm.counter(), 2);
+        assert_eq!(vm.step(), Ok(Some(Instruction::Mul)));
+        assert_eq!(vm.step(), Ok(Some(Instruction::Halt)));
+        assert!(vm.is_halted());
+        assert_eq!(vm.step(), Ok(None));
+        assert_eq!((vm.stack(), vm.steps()), (&[16][..], 4));
+
+        // a failed step stays on the failing instruction
+        let program = assemble("push 1\npush 0\ndiv").unwrap();
+        let mut vm = Vm::new(&program);
+
+        assert!(vm.run().is_err());
+        assert_eq!(vm.counter(), 2);
+        assert!(vm.step().is_err());
+    }
+
+    #[test]
+    fn tracing() {
+        let program = assemble("push 2\npush 3\nadd\nprint").unwrap();
+        let mut out = Vec::new();
+
+        Vm::new(&program).trace(&mut out).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "0000  push 2     [2]\n\
+             0001  push 3     [2, 3]\n\
+             0002  add        [5]\n\
+             0003  print      []\n"
+        );
+
+        let program = assemble("push 1\nswap").unwrap();
+        let mut out = Vec::new();
+
+        match Vm::new(&program).trace(&mut out) {
+            Err(TraceError::Vm(error)) => assert_eq!(error, underflow(1)),
+            other => panic!("expected a VM error, got {other:?}"),
+        }
+
+        // the instructions before the error are traced
+        assert_eq!(String::from_utf8(out).unwrap(), "0000  push 1     [1]\n");
+    }
+
+    #[test]
+    fn assembler_errors() {
+        let error = |line, kind| Err(AssembleError { line, kind });
+
+        assert_eq!(
+            assemble("push 1\nfrob"),
+            error(2, AssembleErrorKind::UnknownInstruction("frob".to_string()))
+        );
+        assert_eq!(
+            assemble("push"),
+            error(1, AssembleErrorKind::MissingOperand("push".to_string()))
+        );
+        assert_eq!(
+            assemble("add 1"),
+            error(1, AssembleErrorKind::UnexpectedOperand("1".to_string()))
+        );
+        assert_eq!(
+            assemble("push one"),
+            error(1, AssembleErrorKind::InvalidOperand("one".to_string()))
+        );
+        assert_eq!(
+            assemble("jmp -1"),
+            error(1, AssembleErrorKind::InvalidOperand("-1".to_string()))
+        );
+        assert_eq!(
+            assemble("; fine\njz nowhere"),
+            error(2, AssembleErrorKind::UndefinedLabel("nowhere".to_string()))
+        );
+        assert_eq!(
+            assemble("a:\na: halt"),
+            error(2, AssembleErrorKind::DuplicateLabel("a".to_string()))
+        );
+        assert_eq!(
+            assemble("1a: halt"),
+            error(1, AssembleErrorKind::InvalidLabel("1a".to_string()))
+        );
+    }
+
+    #[test]
+    fn disassembly_assembles_back() {
+        let source = "
+            start:
+                push 3
+            loop: dup
+                print
+                push 1
+                sub
+                dup
+                jnz loop
+                call end
+                jmp 99
+            end:
+        ";
+        let program: Program = source.parse().unwrap();
+
+        assert_eq!(
+            program.to_string(),
+            [
+                "    push 3",
+                "L1:",
+                "    dup",
+                "    print",
+                "    push 1",
+                "    sub",
+                "    dup",
+                "    jnz L1",
+                "    call L9",
+                "    jmp 99",
+                "L9:",
+            ]
+            .join("\n")
+        );
+        assert_eq!(program.to_string().parse(), Ok(program));
+        assert_eq!(Program::default().to_string(), "");
+    }
+}
@@ -0,0 +1,45 @@
+use rbe_registry::Chapter;
+
+fn mutable_binding() {
+    let mut x = 1;
+
+    println!("before: {x}");
+    x += 1;
+    println!("after: {x}");
+    println!();
+}
+
+fn variable_scope() {
+    let x = "outer";
+
+    {
+        let x = "inner";
+        println!("x in block: {x}");
+    }
+
+    println!("x outside of block: {x}");
+    println!();
+}
+
+fn variable_shadowing() {
+    let x = "outer";
+
+    {
+        let x = "inner";
+        println!("x in block: {x}");
+    }
+    println!("x outside of block: {x}");
+
+    let x = "outer shadowed";
+
+    println!("x outside of block: {x}");
+    println!();
+}
+
+pub const CHAPTER: Chapter = rbe_registry::chapter! {
+    number: 4,
+    name: "variable_bindings",
+    examples: {
+        _: [mutable_binding, variable_scope, variable_shadowing],
+    },
+};
@@ -0,0 +1,9 @@
+// error: E0604
+//
+// From `casting_numbers_to_char`: only u8 can be cast to a char with `as`
+fn main() {
+    let y = 8.2;
+    let y_char = y as char;
+
+    println!("{y_char}");
+}
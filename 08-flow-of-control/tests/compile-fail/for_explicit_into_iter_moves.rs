@@ -0,0 +1,13 @@
+// error: E0382
+//
+// From `for_into_iter_mut`: calling .into_iter explicitly consumes the
+// collection, too
+fn main() {
+    let xs = vec![1, 2, 3];
+
+    for x in xs.into_iter() {
+        println!("x: {x}");
+    }
+
+    println!("{xs:?}");
+}
@@ -0,0 +1,13 @@
+// error: E0382
+//
+// From `for_into_iter_mut`: a for loop implicitly calls .into_iter, which
+// consumes the collection
+fn main() {
+    let xs = vec![1, 2, 3];
+
+    for x in xs {
+        println!("x: {x}");
+    }
+
+    println!("{xs:?}");
+}
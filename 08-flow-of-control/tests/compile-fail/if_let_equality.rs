@@ -0,0 +1,15 @@
+// error: E0369
+//
+// From `if_let_equality`: `==` needs Foo to implement PartialEq, whereas
+// `if let` only needs a pattern
+enum Foo {
+    A,
+}
+
+fn main() {
+    let a = Foo::A;
+
+    if a == Foo::A {
+        println!("does not compile unless Foo implements 'PartialEq'")
+    }
+}
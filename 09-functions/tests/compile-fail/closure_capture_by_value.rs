@@ -0,0 +1,16 @@
+// error: E0382
+//
+// From `closure_capture_by_value`: drop_x moves x out of itself, so it is
+// FnOnce and may only be called once
+use std::mem;
+
+fn main() {
+    let x = Box::new(5);
+    let drop_x = || {
+        println!("dropping x from the heap");
+        mem::drop(x);
+    };
+
+    drop_x();
+    drop_x();
+}
@@ -0,0 +1,16 @@
+// error: E0502
+//
+// From `closure_mut_ref_borrowing`: inc_x holds a mutable borrow
+// of x for as long as it may still be called
+fn main() {
+    let mut x = 5;
+    let mut inc_x = || x += 1;
+
+    inc_x();
+
+    let my_ref = &x;
+
+    inc_x();
+
+    println!("my_ref: {my_ref}");
+}
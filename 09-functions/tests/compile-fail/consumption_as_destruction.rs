@@ -0,0 +1,22 @@
+// error: E0382
+//
+// From `consumption_as_destruction`: `destroy` takes `self`, so
+// heap_ints is moved into it
+#[derive(Debug)]
+struct HeapInts(Box<i32>, Box<i32>);
+
+impl HeapInts {
+    fn destroy(self) {
+        let Self(x, y) = self;
+
+        println!("destroying HeapInts({x}, {y})");
+    }
+}
+
+fn main() {
+    let heap_ints = HeapInts(Box::new(1), Box::new(2));
+
+    heap_ints.destroy();
+
+    println!("thrice: {heap_ints:?}");
+}
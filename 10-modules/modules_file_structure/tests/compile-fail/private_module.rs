@@ -0,0 +1,21 @@
+// error: E0603
+//
+// From `b.rs`: a_b is declared with a private `mod` in a.rs, so it can't be
+// imported from outside a
+mod a {
+    pub mod a_a {
+        pub fn function() {}
+    }
+
+    mod a_b {
+        pub fn function() {}
+    }
+}
+
+use crate::a::a_a;
+use crate::a::a_b;
+
+fn main() {
+    a_a::function();
+    a_b::function();
+}
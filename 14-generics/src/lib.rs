@@ -0,0 +1,456 @@
+use custom_types::geometry::{Circle, HasArea, Point, Polygon, Rect};
+use rbe_registry::Chapter;
+use std::any::type_name;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::ops::Add;
+
+fn type_of<T>(_: T) -> &'static str {
+    type_name::<T>()
+}
+
+fn generic_structs() {
+    // concrete
+    struct A;
+    // concrete
+    struct Single(A);
+    // generic
+    struct SingleGeneric<T>(T);
+
+    let a = A;
+    let single_a = Single(A);
+    let single_generic_a = SingleGeneric(A);
+    let single_nested_single_a = SingleGeneric(Single);
+    let single_generic_i8: SingleGeneric<i8> = SingleGeneric(4);
+
+    println!("type of 'a' is:\n\t{:>10}\n", type_of(a));
+    println!("type of 'single_a' is:\n\t{:>10}\n", type_of(single_a));
+    println!(
+        "type of 'single_generic_a' is:\n\t{:>10}\n",
+        type_of(single_generic_a)
+    );
+    println!(
+        "type of 'single_nested_single_a' is:\n\t{:>10}\n",
+        type_of(single_nested_single_a)
+    );
+    println!(
+        "type of 'single_generic_i8' is:\n\t{:>10}\n",
+        type_of(single_generic_i8)
+    );
+    println!();
+}
+
+fn generic_functions() {
+    struct GenStruct<T>(T);
+
+    fn generic<T>(x: GenStruct<T>) {
+        println!("{}", type_of(x));
+    }
+
+    generic(GenStruct(5)); // implicitly specified type parameter -> i32
+    generic::<i8>(GenStruct(5)); // explicitly specified type parameter -> i8
+    println!();
+}
+
+fn generic_implementation() {
+    #[derive(Debug)]
+    struct GenStruct<T> {
+        val: T,
+    }
+
+    impl<T> GenStruct<T> {
+        fn value(self) -> T {
+            self.val
+        }
+    }
+
+    let x = GenStruct::<i8> { val: 5 };
+    let y = GenStruct { val: 5i16 };
+    let z = GenStruct { val: "foo" };
+
+    println!("x: {:?}", type_of(x.value()));
+    println!("y: {:?}", type_of(y.value()));
+    println!("z: {:?}", type_of(z.value()));
+    println!();
+}
+
+fn generic_traits() {
+    struct Empty;
+    struct Null;
+
+    // A trait that is generic over T
+    trait DoubleDrop<T> {
+        fn drop(self, _: T);
+    }
+
+    // implement the DoubleDrop trait, given a generic caller U
+    impl<T, U> DoubleDrop<T> for U {
+        fn drop(self, _: T) {}
+    }
+
+    let x = Empty;
+    let null = Null;
+
+    x.drop(null);
+    println!();
+}
+
+fn generic_bounds() {
+    struct BoundedType<T: Display> {
+        //                  [1]
+        // 1 - T has the bound 'Display'
+        value: T,
+    }
+
+    fn do_the_print<T: Display>(value: T) {
+        println!("{}", type_of(value))
+    }
+
+    let x = BoundedType { value: "foo" };
+    let y = 2_i16;
+
+    do_the_print(x.value);
+    do_the_print(y);
+
+    println!();
+}
+
+fn generic_bounds_methods() {
+    // HasArea, Circle and Rect come from chapter 3's geometry module. Every
+    // shape there implements HasArea and Display
+    fn print_area<T: HasArea + Display>(shape: &T) {
+        // we can use .area here because the bound HasArea means the type
+        // is guaranteed to have the method
+        let area = shape.area();
+
+        println!("{shape} has area {area:.2}")
+    }
+
+    // the bound on T also gives access to the trait's associated type
+    fn print_bounds<T: HasArea + Display>(shape: &T)
+    where
+        T::Unit: Display,
+    {
+        println!("{shape} fits in {}", shape.bounding_box())
+    }
+
+    let rectangle = Rect::with_size(Point::origin(), 5, 6);
+    let circle = Circle::new(Point::origin(), 5);
+    let triangle = Polygon::new([Point::new(0, 0), Point::new(4, 0), Point::new(4, 3)]);
+
+    print_area(&rectangle);
+    print_area(&circle);
+    print_area(&triangle);
+    print_bounds(&circle);
+    print_bounds(&triangle);
+    println!();
+}
+
+fn generic_bounds_empty_traits() {
+    struct NotFoo {}
+
+    trait EmptyTrait {}
+
+    #[derive(Debug)]
+    struct Foo {}
+
+    impl EmptyTrait for Foo {}
+
+    fn my_bound<T: EmptyTrait + std::fmt::Debug>(value: &T) {
+        println!("Bounded! Value implements EmptyTrait: {:?}", value)
+    }
+
+    let x = Foo {};
+    let _y = NotFoo {};
+
+    my_bound(&x);
+
+    // _y does not implement EmptyTrait
+    //my_bound(&_y);
+    println!()
+}
+
+fn where_clause_for_readability() {
+    trait TraitA {}
+    trait TraitB {}
+    trait TraitC {}
+    trait TraitD {}
+
+    #[derive(Debug)]
+    struct X;
+
+    impl TraitA for X {}
+    impl TraitB for X {}
+
+    #[derive(Debug)]
+    struct Y;
+
+    impl TraitC for Y {}
+    impl TraitD for Y {}
+
+    fn foo<T: TraitA + TraitB + std::fmt::Debug, U: TraitC + TraitD + std::fmt::Debug>(
+        x: &T,
+        y: &U,
+    ) {
+        println!("{x:?} {y:?}")
+    }
+
+    // bar has the same type signature, but the `where` clause makes it
+    // easier to read
+    fn bar<T, U>(x: &T, y: &U)
+    where
+        T: TraitA + TraitB + std::fmt::Debug,
+        U: TraitC + TraitD + std::fmt::Debug,
+    {
+        println!("{x:?} {y:?}")
+    }
+
+    let x = X;
+    let y = Y;
+
+    foo(&x, &y);
+    bar(&x, &y);
+
+    println!()
+}
+
+fn where_clause_when_required() {
+    trait Debuggable {
+        fn debug_in_option(self);
+        fn get_debug_string(self) -> String;
+    }
+
+    impl<T> Debuggable for T
+    // we can't set this bound where T is first mentioned, because
+    // we want the bound specified for Option<T>, as that is what
+    // we're printing
+    where
+        Option<T>: std::fmt::Debug,
+    {
+        fn debug_in_option(self) {
+            println!("{:?}", Some(self))
+        }
+
+        fn get_debug_string(self) -> String {
+            // Without the bound on Option, we wouldn't be able to use
+            // Some(self) here
+            // Furthermore, to use `self`, we'd need a bound on T, too
+            let result = format!("{:?}", Some(self));
+
+            result
+        }
+    }
+
+    let xs = vec![1, 2, 3];
+    let ys = vec![1, 2, 3];
+
+    xs.debug_in_option();
+
+    println!("{}", ys.get_debug_string());
+    println!();
+}
+
+fn generic_associated_types_before() {
+    #[derive(Debug)]
+    struct Container(i32, i32);
+
+    // A trait with 2 generic types
+    trait Contains<A, B> {
+        // determine if an instance that implements this traiit
+        // contains 2 values
+        fn contains(&self, _: &A, _: &B) -> bool;
+        // get the first value from the instance
+        // - no need for A or B
+        fn first(&self) -> i32;
+        // get the last value from the instance
+        // - no need for A or B
+        fn last(&self) -> i32;
+    }
+
+    impl Contains<i32, i32> for Container {
+        fn contains(&self, x: &i32, y: &i32) -> bool {
+            &self.0 == x && &self.1 == y
+        }
+
+        fn first(&self) -> i32 {
+            self.0
+        }
+
+        fn last(&self) -> i32 {
+            self.1
+        }
+    }
+
+    // A, B, and C need to be specified
+    fn difference<A, B, C>(container: &C) -> i32
+    where
+        C: Contains<A, B>,
+    {
+        container.last() - container.first()
+    }
+
+    let x = Container(4, 2);
+
+    println!("x contains 4 and 2: {:?}", x.contains(&4, &2));
+    println!("x contains 5 and 2: {:?}", x.contains(&5, &2));
+    println!("x difference: {:?}", difference(&x));
+    println!();
+}
+
+fn generic_associated_types_after() {
+    #[derive(Debug)]
+    struct Container(i32, i32);
+
+    trait Contains {
+        // 'Contains' is a trait with 2 associated types,
+        // i.e. output types
+        type A;
+        type B;
+
+        // use Self::A to reference associated type
+        fn contains(&self, _: &Self::A, _: &Self::B) -> bool;
+
+        fn first(&self) -> i32;
+
+        fn last(&self) -> i32;
+    }
+
+    impl Contains for Container {
+        // 'Container' implements 'Contains', and is thus the input type.
+        // 'Container' is defined with 2 i32 values, which are thus 'Contains's
+        // output types
+        type A = i32;
+        type B = i32;
+
+        // we can either use the type, or Self::[associated_type] to specify
+        // the output type
+        fn contains(&self, x: &Self::A, y: &i32) -> bool {
+            &self.0 == x && &self.1 == y
+        }
+
+        fn first(&self) -> i32 {
+            self.0
+        }
+
+        fn last(&self) -> i32 {
+            self.1
+        }
+    }
+
+    // because 'Contains' now uses associated types, and 'Container' needs to
+    // implmeent the types, we no longer need to specify all the types
+    fn difference<C: Contains>(container: &C) -> i32 {
+        container.last() - container.first()
+    }
+
+    let x = Container(4, 2);
+
+    println!("x contains 4 and 2: {:?}", x.contains(&4, &2));
+    println!("x contains 5 and 2: {:?}", x.contains(&5, &2));
+    println!("x difference: {:?}", difference(&x));
+    println!();
+}
+
+fn phantom_types() {
+    #[derive(Debug, PartialEq)]
+    struct PhantomTuple<A, B>(A, PhantomData<B>);
+
+    impl<A, B> PhantomTuple<A, B> {
+        fn new(value: A, _: B) -> Self {
+            Self(value, PhantomData)
+        }
+    }
+
+    let tuple_x: PhantomTuple<char, i32> = PhantomTuple::new('A', 5);
+    let tuple_y: PhantomTuple<char, f64> = PhantomTuple::new('A', 5.0);
+
+    println!("tuple_x: {:?}", &tuple_x);
+    println!("tuple_y: {:?}", &tuple_y);
+
+    // The following comparison raises compile-time errors, because
+    // the phanatom types PhantomData<i32> and PhantomData<f64> are
+    // not comparable
+    //println!("tuple_x == tuple_y: {}", tuple_x == tuple_y);
+
+    println!();
+
+    #[derive(Debug, PartialEq)]
+    struct PhantomStruct<A, B> {
+        value: A,
+        phantom: PhantomData<B>,
+    }
+
+    impl<A, B> PhantomStruct<A, B> {
+        fn new(value: A, _: B) -> Self {
+            Self {
+                value,
+                phantom: PhantomData,
+            }
+        }
+    }
+
+    let struct_x: PhantomStruct<char, i32> = PhantomStruct::new('A', 5);
+    let struct_y: PhantomStruct<char, f64> = PhantomStruct::new('A', 5.0);
+
+    println!("struct_x: {:?}", &struct_x);
+    println!("struct_y: {:?}", &struct_y);
+
+    // As with the tuple structs, this line raises compile-time errors, too
+    //println!("struct_x == struct_y: {}", struct_x == struct_y);
+
+    println!();
+}
+
+fn phantom_types_unit_clarification() {
+    // These enums are called 'void enumerations'
+    // They appear to be an old style of declaring types without values,
+    // and are analagous to unit-like structs
+    #[derive(Debug, Clone, Copy)]
+    enum Gram {}
+
+    #[derive(Debug, Clone, Copy)]
+    enum Ounce {}
+
+    #[derive(Debug, Clone, Copy)]
+    struct Weight<Unit>(f64, PhantomData<Unit>);
+
+    impl<Unit> Add for Weight<Unit> {
+        type Output = f64;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            self.0 + rhs.0
+        }
+    }
+
+    let weight_in_grams: Weight<Gram> = Weight(5.0, PhantomData);
+    let weight_in_ounces: Weight<Ounce> = Weight(6.0, PhantomData);
+
+    println!("total grams: {}", weight_in_grams + weight_in_grams);
+    println!("total ounces: {}", weight_in_ounces + weight_in_ounces);
+
+    // We can't add these values - the PhantomData in the Weight definition
+    // ensures we can only add two units of the same type
+    //println!("total mixed: {}", weight_in_grams + weight_in_ounces);
+}
+
+pub const CHAPTER: Chapter = rbe_registry::chapter! {
+    number: 14,
+    name: "generics",
+    examples: {
+        _: [
+            generic_structs,
+            generic_functions,
+            generic_implementation,
+            generic_traits,
+            generic_bounds,
+            generic_bounds_methods,
+            generic_bounds_empty_traits,
+            where_clause_for_readability,
+            where_clause_when_required,
+            generic_associated_types_before,
+            generic_associated_types_after,
+            phantom_types,
+            phantom_types_unit_clarification,
+        ],
+    },
+};
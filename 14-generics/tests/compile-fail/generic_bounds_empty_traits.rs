@@ -0,0 +1,18 @@
+// error: E0277
+//
+// From `generic_bounds_empty_traits`: NotFoo doesn't implement EmptyTrait, so
+// it doesn't satisfy my_bound's bound
+trait EmptyTrait {}
+
+#[derive(Debug)]
+struct NotFoo {}
+
+fn my_bound<T: EmptyTrait + std::fmt::Debug>(value: &T) {
+    println!("Bounded! Value implements EmptyTrait: {:?}", value)
+}
+
+fn main() {
+    let y = NotFoo {};
+
+    my_bound(&y);
+}
@@ -0,0 +1,24 @@
+// error: E0308
+//
+// From `phantom_types`: as with the tuple struct, the phantom type parameter
+// makes the two structs different types
+use std::marker::PhantomData;
+
+#[derive(Debug, PartialEq)]
+struct PhantomStruct<A, B> {
+    value: A,
+    phantom: PhantomData<B>,
+}
+
+fn main() {
+    let struct_x: PhantomStruct<char, i32> = PhantomStruct {
+        value: 'A',
+        phantom: PhantomData,
+    };
+    let struct_y: PhantomStruct<char, f64> = PhantomStruct {
+        value: 'A',
+        phantom: PhantomData,
+    };
+
+    println!("struct_x == struct_y: {}", struct_x == struct_y);
+}
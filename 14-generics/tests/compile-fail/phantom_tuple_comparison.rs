@@ -0,0 +1,15 @@
+// error: E0308
+//
+// From `phantom_types`: PhantomTuple<char, i32> and PhantomTuple<char, f64>
+// are different types, so they can't be compared
+use std::marker::PhantomData;
+
+#[derive(Debug, PartialEq)]
+struct PhantomTuple<A, B>(A, PhantomData<B>);
+
+fn main() {
+    let tuple_x: PhantomTuple<char, i32> = PhantomTuple('A', PhantomData);
+    let tuple_y: PhantomTuple<char, f64> = PhantomTuple('A', PhantomData);
+
+    println!("tuple_x == tuple_y: {}", tuple_x == tuple_y);
+}
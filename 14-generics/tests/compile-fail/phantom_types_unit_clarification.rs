@@ -0,0 +1,30 @@
+// error: E0308
+//
+// From `phantom_types_unit_clarification`: Add is only implemented for two
+// weights with the same unit
+use std::marker::PhantomData;
+use std::ops::Add;
+
+#[derive(Debug, Clone, Copy)]
+enum Gram {}
+
+#[derive(Debug, Clone, Copy)]
+enum Ounce {}
+
+#[derive(Debug, Clone, Copy)]
+struct Weight<Unit>(f64, PhantomData<Unit>);
+
+impl<Unit> Add for Weight<Unit> {
+    type Output = f64;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.0 + rhs.0
+    }
+}
+
+fn main() {
+    let weight_in_grams: Weight<Gram> = Weight(5.0, PhantomData);
+    let weight_in_ounces: Weight<Ounce> = Weight(6.0, PhantomData);
+
+    println!("total mixed: {}", weight_in_grams + weight_in_ounces);
+}
@@ -0,0 +1,26 @@
+// error: E0502
+//
+// From `aliasing`: immutable borrows taken before a mutable borrow can't be
+// used again after it
+#[derive(Debug)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn print_point(point: &Point) {
+    println!("points are ({} {})", point.x, point.y)
+}
+
+fn main() {
+    let mut point = Point { x: 5, y: 10 };
+    let borrow_a = &point;
+
+    {
+        let mutable_borrow = &mut point;
+
+        mutable_borrow.x += 1;
+    }
+
+    print_point(borrow_a);
+}
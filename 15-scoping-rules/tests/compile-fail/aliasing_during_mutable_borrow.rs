@@ -0,0 +1,23 @@
+// error: E0502
+//
+// From `aliasing`: immutable borrows can't be used while a mutable borrow of
+// the same value is live
+#[derive(Debug)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn print_point(point: &Point) {
+    println!("points are ({} {})", point.x, point.y)
+}
+
+fn main() {
+    let mut point = Point { x: 5, y: 10 };
+    let borrow_a = &point;
+    let mutable_borrow = &mut point;
+
+    print_point(borrow_a);
+
+    mutable_borrow.x += 1;
+}
@@ -0,0 +1,19 @@
+// error: E0505
+//
+// From `borrow_and_destroy`: x_heaped can't be moved while a reference to it
+// is still used further down
+fn i_will_destroy(x: Box<i32>) {
+    println!("x is {} and about to be destroyed", x);
+}
+
+fn i_will_borrow(x: &i32) {
+    println!("x is {} and borrowed", x);
+}
+
+fn main() {
+    let x_heaped = Box::new(6);
+    let ref_x_heaped = &x_heaped;
+
+    i_will_destroy(x_heaped);
+    i_will_borrow(ref_x_heaped);
+}
@@ -0,0 +1,15 @@
+// error: E0106
+//
+// From `lifetime_explicit_multiple_parameters`: with two borrowed parameters,
+// elision can't tell which lifetime the returned reference has
+fn implicit_multiple_with_return(x: &i32, y: &i32) -> &i32 {
+    println!("x: {x}, y: {y}");
+
+    x
+}
+
+fn main() {
+    let (x, y) = (6, 43);
+
+    implicit_multiple_with_return(&x, &y);
+}
@@ -0,0 +1,16 @@
+// error: E0597
+//
+// From `lifetime_static_bounded_int`: x is 'static as an owned value, but a
+// reference to it only lives as long as x
+use std::fmt::Debug;
+
+fn print_thing(x: impl Debug + 'static) {
+    println!("x: {x:?}")
+}
+
+fn main() {
+    let x = 5;
+
+    print_thing(x);
+    print_thing(&x);
+}
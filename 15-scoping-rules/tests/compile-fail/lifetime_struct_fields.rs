@@ -0,0 +1,13 @@
+// error: E0716
+//
+// From `lifetime_struct_fields`: the String is a temporary which is dropped at
+// the end of the statement, while BorrowedTuple still refers to it
+#[derive(Debug)]
+struct BorrowedTuple<'a>(&'a String);
+
+fn main() {
+    let invalid_borrow;
+    invalid_borrow = BorrowedTuple(&String::from("foo"));
+
+    println!("invalid_borrow: {invalid_borrow:?}");
+}
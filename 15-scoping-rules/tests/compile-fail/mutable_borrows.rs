@@ -0,0 +1,26 @@
+// error: E0596
+//
+// From `mutable_borrows`: a mutable reference can't be taken to a binding that
+// wasn't declared with `mut`
+#[derive(Debug)]
+struct Book {
+    title: &'static str,
+    year: i32,
+}
+
+fn set_year(book: &mut Book, year: i32) -> &mut Book {
+    book.year = year;
+
+    book
+}
+
+fn main() {
+    let immutable_book = Book {
+        title: "Moby Dick",
+        year: 1921,
+    };
+
+    set_year(&mut immutable_book, 1918);
+
+    println!("{} {}", immutable_book.title, immutable_book.year);
+}
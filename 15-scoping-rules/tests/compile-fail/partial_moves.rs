@@ -0,0 +1,20 @@
+// error: E0382
+//
+// From `partial_moves`: destructuring moved `name` out of person, so person
+// can no longer be used as a whole
+#[derive(Debug)]
+struct Person {
+    name: String,
+    age: Box<i32>,
+}
+
+fn main() {
+    let person = Person {
+        name: String::from("sam"),
+        age: Box::new(6),
+    };
+    let Person { name, ref age } = person;
+
+    println!("person's name is {name}, and age is {age}");
+    println!("person's age is {:?}", person);
+}
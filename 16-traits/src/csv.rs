@@ -0,0 +1,545 @@
+// An RFC 4180 CSV reader and writer, grown from `parse_csv_using_bounds` in
+// `impl_trait_argument_real_world`.
+//
+// Splitting each line on `,` breaks as soon as a field is quoted:
+//
+//     name,quote
+//     "Doe, Jane","She said ""hi""
+//     and left"
+//
+// is 2 records of 2 fields each - the second record spans two lines, and its
+// fields contain a delimiter, escaped quotes, and a line break.
+//
+// Reading is streamed over any `BufRead`, one record at a time, and records can
+// be decoded into types implementing `FromStr` via `FromRecord`.
+use std::error;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::ops::Index;
+use std::str::FromStr;
+
+// the characters that structure a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dialect {
+    pub delimiter: char,
+    pub quote: char,
+    // only used when writing - reading accepts both `\n` and `\r\n`
+    pub terminator: &'static str,
+}
+
+impl Default for Dialect {
+    fn default() -> Self {
+        Dialect {
+            delimiter: ',',
+            quote: '"',
+            terminator: "\r\n",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ErrorKind {
+    Io(io::Error),
+    // a quoted field is still open at the end of the input
+    UnterminatedQuote,
+    // a quote in the middle of an unquoted field, e.g. `a"b`
+    UnexpectedQuote,
+    // anything other than a delimiter or the end of a line after a closing
+    // quote, e.g. `"a"b`
+    ExpectedDelimiter(char),
+    // a record with a different number of fields to the first record
+    FieldCount { expected: usize, found: usize },
+    MissingField(usize),
+    UnknownColumn(String),
+    // a field couldn't be decoded with `FromStr`
+    Parse { field: String, message: String },
+}
+
+#[derive(Debug)]
+pub struct Error {
+    pub kind: ErrorKind,
+    // both 1-based. 0 when the error isn't tied to a position, e.g. for I/O
+    // errors
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Error {
+    fn new(kind: ErrorKind, line: usize, column: usize) -> Error {
+        Error { kind, line, column }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ErrorKind::Io(e) => return write!(f, "{e}"),
+            ErrorKind::UnterminatedQuote => write!(f, "unterminated quoted field"),
+            ErrorKind::UnexpectedQuote => write!(f, "unexpected quote in unquoted field"),
+            ErrorKind::ExpectedDelimiter(c) => {
+                write!(f, "expected a delimiter after closing quote, found '{c}'")
+            }
+            ErrorKind::FieldCount { expected, found } => {
+                write!(f, "expected {expected} fields, found {found}")
+            }
+            ErrorKind::MissingField(index) => write!(f, "no field at index {index}"),
+            ErrorKind::UnknownColumn(name) => write!(f, "no column named '{name}'"),
+            ErrorKind::Parse { field, message } => write!(f, "invalid field '{field}': {message}"),
+        }?;
+
+        write!(f, " at line {}, column {}", self.line, self.column)
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match &self.kind {
+            ErrorKind::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::new(ErrorKind::Io(e), 0, 0)
+    }
+}
+
+// so that CSV can be read wherever an `io::Result` is expected
+impl From<Error> for io::Error {
+    fn from(e: Error) -> Self {
+        match e.kind {
+            ErrorKind::Io(e) => e,
+            _ => io::Error::new(io::ErrorKind::InvalidData, e),
+        }
+    }
+}
+
+// the fields of a single record, and where each field started
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    fields: Vec<String>,
+    positions: Vec<(usize, usize)>,
+}
+
+impl Record {
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.fields.get(index).map(String::as_str)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.fields.iter().map(String::as_str)
+    }
+
+    // the line the record starts on
+    pub fn line(&self) -> usize {
+        self.positions.first().map_or(0, |&(line, _)| line)
+    }
+
+    pub fn into_fields(self) -> Vec<String> {
+        self.fields
+    }
+
+    // decode the field at `index` using `FromStr`, pointing at the field if it
+    // fails
+    pub fn parse<T>(&self, index: usize) -> Result<T, Error>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let (line, column) = self
+            .positions
+            .get(index)
+            .copied()
+            .unwrap_or((self.line(), 1));
+        let field =
+            self.get(index)
+                .ok_or(Error::new(ErrorKind::MissingField(index), line, column))?;
+
+        field.parse().map_err(|e: T::Err| {
+            let kind = ErrorKind::Parse {
+                field: field.to_string(),
+                message: e.to_string(),
+            };
+
+            Error::new(kind, line, column)
+        })
+    }
+
+    // decode the field in the column named `name` in `headers`
+    pub fn parse_named<T>(&self, headers: &Record, name: &str) -> Result<T, Error>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let index = headers
+            .iter()
+            .position(|header| header == name)
+            .ok_or(Error::new(
+                ErrorKind::UnknownColumn(name.to_string()),
+                self.line(),
+                1,
+            ))?;
+
+        self.parse(index)
+    }
+}
+
+impl Index<usize> for Record {
+    type Output = str;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.fields[index]
+    }
+}
+
+// types that can be built from a record
+pub trait FromRecord: Sized {
+    fn from_record(record: &Record) -> Result<Self, Error>;
+}
+
+// types that can be written as a record
+pub trait ToRecord {
+    fn to_record(&self) -> Vec<String>;
+}
+
+// tuples of `FromStr` types decode one field per element, e.g.
+// `(String, u32, f64)`
+macro_rules! impl_tuple_records {
+    ($(($($t: ident: $index: tt),+)),+ $(,)?) => {
+        $(
+            impl<$($t),+> FromRecord for ($($t,)+)
+            where
+                $($t: FromStr, $t::Err: fmt::Display),+
+            {
+                fn from_record(record: &Record) -> Result<Self, Error> {
+                    Ok(($(record.parse::<$t>($index)?,)+))
+                }
+            }
+
+            impl<$($t: fmt::Display),+> ToRecord for ($($t,)+) {
+                fn to_record(&self) -> Vec<String> {
+                    vec![$(self.$index.to_string()),+]
+                }
+            }
+        )+
+    };
+}
+
+impl_tuple_records!(
+    (A: 0),
+    (A: 0, B: 1),
+    (A: 0, B: 1, C: 2),
+    (A: 0, B: 1, C: 2, D: 3),
+    (A: 0, B: 1, C: 2, D: 3, E: 4),
+    (A: 0, B: 1, C: 2, D: 3, E: 4, F: 5),
+);
+
+impl FromRecord for Record {
+    fn from_record(record: &Record) -> Result<Self, Error> {
+        Ok(record.clone())
+    }
+}
+
+impl FromRecord for Vec<String> {
+    fn from_record(record: &Record) -> Result<Self, Error> {
+        Ok(record.fields.clone())
+    }
+}
+
+pub struct Reader<R> {
+    src: R,
+    dialect: Dialect,
+    has_headers: bool,
+    headers: Option<Record>,
+    // allow records to have different numbers of fields
+    flexible: bool,
+    expected_len: Option<usize>,
+    // the number of lines read so far
+    line: usize,
+}
+
+impl<R: BufRead> Reader<R> {
+    // a reader for comma-separated records without a header row
+    pub fn new(src: R) -> Reader<R> {
+        Reader {
+            src,
+            dialect: Dialect::default(),
+            has_headers: false,
+            headers: None,
+            flexible: false,
+            expected_len: None,
+            line: 0,
+        }
+    }
+
+    pub fn with_dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    // treat the first record as the names of the columns, rather than data
+    pub fn with_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+
+    pub fn with_flexible(mut self, flexible: bool) -> Self {
+        self.flexible = flexible;
+        self
+    }
+
+    // the header row, read on first use. `None` if the reader has no headers,
+    // or the input is empty
+    pub fn headers(&mut self) -> Result<Option<&Record>, Error> {
+        if self.has_headers && self.headers.is_none() {
+            self.headers = self.read_raw()?;
+        }
+
+        Ok(self.headers.as_ref())
+    }
+
+    pub fn read_record(&mut self) -> Result<Option<Record>, Error> {
+        self.headers()?;
+        self.read_raw()
+    }
+
+    // decode each remaining record into `T`
+    pub fn decode<T: FromRecord>(&mut self) -> impl Iterator<Item = Result<T, Error>> + '_ {
+        self.by_ref()
+            .map(|record| record.and_then(|record| T::from_record(&record)))
+    }
+
+    pub fn into_inner(self) -> R {
+        self.src
+    }
+
+    fn read_line(&mut self, buf: &mut String) -> Result<bool, Error> {
+        buf.clear();
+
+        if self.src.read_line(buf)? == 0 {
+            return Ok(false);
+        }
+
+        self.line += 1;
+
+        Ok(true)
+    }
+
+    fn read_raw(&mut self) -> Result<Option<Record>, Error> {
+        #[derive(PartialEq)]
+        enum State {
+            StartOfField,
+            Unquoted,
+            Quoted,
+            // just after a quote inside a quoted field - either the end of the
+            // field, or the first half of an escaped quote
+            QuoteInQuoted,
+        }
+
+        let Dialect {
+            delimiter, quote, ..
+        } = self.dialect;
+        let mut buf = String::new();
+
+        // skip blank lines between records
+        loop {
+            if !self.read_line(&mut buf)? {
+                return Ok(None);
+            }
+
+            if !buf.trim_end_matches(['\r', '\n']).is_empty() {
+                break;
+            }
+        }
+
+        let mut record = Record {
+            fields: vec![],
+            positions: vec![],
+        };
+        let mut field = String::new();
+        let mut state = State::StartOfField;
+        // where the current quoted field opened, for unterminated quotes
+        let mut quote_start = (0, 0);
+
+        loop {
+            let content = buf.trim_end_matches(['\r', '\n']);
+            let terminator = &buf[content.len()..];
+
+            for (column, c) in content.chars().enumerate().map(|(i, c)| (i + 1, c)) {
+                let position = (self.line, column);
+
+                match state {
+                    State::StartOfField => {
+                        record.positions.push(position);
+
+                        if c == quote {
+                            quote_start = position;
+                            state = State::Quoted;
+                        } else if c == delimiter {
+                            record.fields.push(std::mem::take(&mut field));
+                        } else {
+                            field.push(c);
+                            state = State::Unquoted;
+                        }
+                    }
+                    State::Unquoted if c == delimiter => {
+                        record.fields.push(std::mem::take(&mut field));
+                        state = State::StartOfField;
+                    }
+                    State::Unquoted if c == quote => {
+                        return Err(Error::new(ErrorKind::UnexpectedQuote, self.line, column));
+                    }
+                    State::Unquoted => field.push(c),
+                    State::Quoted if c == quote => state = State::QuoteInQuoted,
+                    State::Quoted => field.push(c),
+                    State::QuoteInQuoted if c == quote => {
+                        field.push(quote);
+                        state = State::Quoted;
+                    }
+                    State::QuoteInQuoted if c == delimiter => {
+                        record.fields.push(std::mem::take(&mut field));
+                        state = State::StartOfField;
+                    }
+                    State::QuoteInQuoted => {
+                        return Err(Error::new(
+                            ErrorKind::ExpectedDelimiter(c),
+                            self.line,
+                            column,
+                        ));
+                    }
+                }
+            }
+
+            if state != State::Quoted {
+                break;
+            }
+
+            // a line break inside quotes is part of the field
+            field.push_str(terminator);
+
+            if !self.read_line(&mut buf)? {
+                let (line, column) = quote_start;
+
+                return Err(Error::new(ErrorKind::UnterminatedQuote, line, column));
+            }
+        }
+
+        // a trailing delimiter is followed by an empty field
+        if state == State::StartOfField {
+            record.positions.push((
+                self.line,
+                buf.trim_end_matches(['\r', '\n']).chars().count() + 1,
+            ));
+        }
+
+        record.fields.push(field);
+        self.check_len(&record)?;
+
+        Ok(Some(record))
+    }
+
+    fn check_len(&mut self, record: &Record) -> Result<(), Error> {
+        match self.expected_len {
+            _ if self.flexible => Ok(()),
+            None => {
+                self.expected_len = Some(record.len());
+                Ok(())
+            }
+            Some(expected) if expected == record.len() => Ok(()),
+            Some(expected) => Err(Error::new(
+                ErrorKind::FieldCount {
+                    expected,
+                    found: record.len(),
+                },
+                record.line(),
+                1,
+            )),
+        }
+    }
+}
+
+// iterate over the records after the header row
+impl<R: BufRead> Iterator for Reader<R> {
+    type Item = Result<Record, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().transpose()
+    }
+}
+
+pub struct Writer<W: Write> {
+    dst: W,
+    dialect: Dialect,
+}
+
+impl<W: Write> Writer<W> {
+    pub fn new(dst: W) -> Writer<W> {
+        Writer {
+            dst,
+            dialect: Dialect::default(),
+        }
+    }
+
+    pub fn with_dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    // write one record, quoting any fields that need it
+    pub fn write_record<I>(&mut self, fields: I) -> io::Result<()>
+    where
+        I: IntoIterator,
+        I::Item: fmt::Display,
+    {
+        let Dialect {
+            delimiter,
+            quote,
+            terminator,
+        } = self.dialect;
+
+        let fields: Vec<String> = fields.into_iter().map(|field| field.to_string()).collect();
+        // a record with a single empty field would otherwise be a blank line,
+        // which readers skip
+        let is_blank = matches!(fields.as_slice(), [field] if field.is_empty());
+
+        for (i, field) in fields.iter().enumerate() {
+            if i > 0 {
+                write!(self.dst, "{delimiter}")?;
+            }
+
+            let needs_quotes = is_blank || field.contains([delimiter, quote, '\r', '\n']);
+
+            if needs_quotes {
+                let escaped = field.replace(quote, &format!("{quote}{quote}"));
+
+                write!(self.dst, "{quote}{escaped}{quote}")?;
+            } else {
+                write!(self.dst, "{field}")?;
+            }
+        }
+
+        write!(self.dst, "{terminator}")
+    }
+
+    pub fn encode<T: ToRecord>(&mut self, value: &T) -> io::Result<()> {
+        self.write_record(value.to_record())
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.dst.flush()
+    }
+
+    pub fn into_inner(self) -> W {
+        self.dst
+    }
+}
@@ -0,0 +1,11 @@
+// error: E0107
+//
+// From `impl_trait_as_argument`: 'impl Trait' arguments are anonymous, so
+// there's no type parameter for turbofish syntax to set
+fn print_using_impl_trait(x: impl std::fmt::Debug) {
+    println!("debug using impl trait: {x:?}");
+}
+
+fn main() {
+    print_using_impl_trait::<i32>(5);
+}
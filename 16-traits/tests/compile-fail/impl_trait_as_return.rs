@@ -0,0 +1,15 @@
+// error: E0107
+//
+// From `impl_trait_as_return`: the function only has an anonymous type
+// parameter for its 'impl Trait' argument, so turbofish can't set it
+fn id_as_impl_trait_return(
+    x: impl std::ops::Add + std::fmt::Debug,
+) -> impl std::ops::Add + std::fmt::Debug {
+    x
+}
+
+fn main() {
+    let z = id_as_impl_trait_return::<i8>(1);
+
+    println!("z: {z:?}");
+}
@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod csv_tests {
+    use traits::csv::{Dialect, Error, ErrorKind, Reader, Record, Writer};
+
+    fn read(src: &str) -> Result<Vec<Vec<String>>, Error> {
+        Reader::new(src.as_bytes())
+            .map(|record| record.map(Record::into_fields))
+            .collect()
+    }
+
+    fn fields(rows: &[&[&str]]) -> Vec<Vec<String>> {
+        rows.iter()
+            .map(|row| row.iter().map(|field| field.to_string()).collect())
+            .collect()
+    }
+
+    fn write(rows: &[&[&str]], dialect: Dialect) -> String {
+        let mut writer = Writer::new(vec![]).with_dialect(dialect);
+
+        for row in rows {
+            writer.write_record(row.iter()).unwrap();
+        }
+
+        String::from_utf8(writer.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn plain_records() {
+        assert_eq!(
+            read("a,b,c\nd,e,f").unwrap(),
+            fields(&[&["a", "b", "c"], &["d", "e", "f"]])
+        );
+        assert_eq!(
+            read("a,b\r\nc,d\r\n").unwrap(),
+            fields(&[&["a", "b"], &["c", "d"]])
+        );
+        // spaces are part of a field
+        assert_eq!(read(" a , b ").unwrap(), fields(&[&[" a ", " b "]]));
+        assert_eq!(read("").unwrap(), fields(&[]));
+    }
+
+    #[test]
+    fn empty_fields_and_blank_lines() {
+        assert_eq!(
+            read(",a,\n\n,,\n").unwrap(),
+            fields(&[&["", "a", ""], &["", "", ""]])
+        );
+        assert_eq!(read("\"\"\n").unwrap(), fields(&[&[""]]));
+    }
+
+    #[test]
+    fn quoted_fields() {
+        let src = "\"Doe, Jane\",\"She said \"\"hi\"\"\r\nand left\"\r\nx,\"\"\"\"\r\n";
+
+        assert_eq!(
+            read(src).unwrap(),
+            fields(&[&["Doe, Jane", "She said \"hi\"\r\nand left"], &["x", "\""]])
+        );
+    }
+
+    #[test]
+    fn error_positions() {
+        let e = read("a,b\nc,d\"e\n").unwrap_err();
+
+        assert!(matches!(e.kind, ErrorKind::UnexpectedQuote));
+        assert_eq!((e.line, e.column), (2, 4));
+
+        let e = read("a,\"b\"c\n").unwrap_err();
+
+        assert!(matches!(e.kind, ErrorKind::ExpectedDelimiter('c')));
+        assert_eq!((e.line, e.column), (1, 6));
+
+        let e = read("a,b\nc,\"d\ne\n").unwrap_err();
+
+        assert!(matches!(e.kind, ErrorKind::UnterminatedQuote));
+        assert_eq!((e.line, e.column), (2, 3));
+        assert_eq!(
+            e.to_string(),
+            "unterminated quoted field at line 2, column 3"
+        );
+
+        let e = read("a,b\n\"c\nd\",e,f\n").unwrap_err();
+
+        assert!(matches!(
+            e.kind,
+            ErrorKind::FieldCount {
+                expected: 2,
+                found: 3
+            }
+        ));
+        assert_eq!((e.line, e.column), (2, 1));
+    }
+
+    #[test]
+    fn flexible_records() {
+        let records: Vec<_> = Reader::new("a\nb,c\n".as_bytes())
+            .with_flexible(true)
+            .map(|record| record.unwrap().len())
+            .collect();
+
+        assert_eq!(records, [1, 2]);
+    }
+
+    #[test]
+    fn headers() {
+        let mut reader = Reader::new("name,age\nann,31\nbob,27\n".as_bytes()).with_headers(true);
+        let headers = reader.headers().unwrap().unwrap().clone();
+
+        assert_eq!(headers.iter().collect::<Vec<_>>(), ["name", "age"]);
+
+        let ages: Vec<u8> = reader
+            .map(|record| record.unwrap().parse_named(&headers, "age").unwrap())
+            .collect();
+
+        assert_eq!(ages, [31, 27]);
+    }
+
+    #[test]
+    fn typed_decoding() {
+        let mut reader = Reader::new("1,2.5,x\n3,-1,y\n".as_bytes());
+        let rows: Vec<(u32, f64, char)> = reader.decode().collect::<Result<_, _>>().unwrap();
+
+        assert_eq!(rows, [(1, 2.5, 'x'), (3, -1.0, 'y')]);
+
+        let mut reader = Reader::new("a,b\n1,oops\n".as_bytes()).with_headers(true);
+        let e = reader.decode::<(i32, i32)>().next().unwrap().unwrap_err();
+
+        assert!(matches!(&e.kind, ErrorKind::Parse { field, .. } if field == "oops"));
+        assert_eq!((e.line, e.column), (2, 3));
+
+        let mut reader = Reader::new("1\n".as_bytes());
+        let e = reader.decode::<(i32, i32)>().next().unwrap().unwrap_err();
+
+        assert!(matches!(e.kind, ErrorKind::MissingField(1)));
+    }
+
+    #[test]
+    fn custom_dialect() {
+        let dialect = Dialect {
+            delimiter: ';',
+            quote: '\'',
+            terminator: "\n",
+        };
+        let records: Vec<_> = Reader::new("'a;b';c\n'it''s';d\n".as_bytes())
+            .with_dialect(dialect)
+            .map(|record| record.unwrap().into_fields())
+            .collect();
+
+        assert_eq!(records, fields(&[&["a;b", "c"], &["it's", "d"]]));
+        assert_eq!(
+            write(&[&["a;b", "it's", "c,d"]], dialect),
+            "'a;b';'it''s';c,d\n"
+        );
+    }
+
+    #[test]
+    fn writer_quotes_only_when_needed() {
+        let rows: &[&[&str]] = &[&["a", "b,c", "say \"hi\"", "multi\nline"], &[""]];
+        let written = write(rows, Dialect::default());
+
+        assert_eq!(
+            written,
+            "a,\"b,c\",\"say \"\"hi\"\"\",\"multi\nline\"\r\n\"\"\r\n"
+        );
+
+        let mut writer = Writer::new(vec![]);
+
+        writer.encode(&("x", 1, 2.5)).unwrap();
+
+        assert_eq!(writer.into_inner(), b"x,1,2.5\r\n");
+    }
+
+    #[test]
+    fn round_trip() {
+        let rows: &[&[&str]] = &[
+            &["name", "notes"],
+            &["Doe, Jane", "\"quoted\"\r\nsecond line"],
+            &["", "trailing,"],
+        ];
+
+        assert_eq!(
+            read(&write(rows, Dialect::default())).unwrap(),
+            fields(rows)
+        );
+    }
+}
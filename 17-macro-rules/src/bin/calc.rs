@@ -0,0 +1,10 @@
+// an interactive calculator - run with `cargo run -p macro_rules --bin calc`
+use std::io;
+
+use macro_rules::calculator::repl::Repl;
+
+fn main() -> io::Result<()> {
+    println!("calculator - type :help for commands, :quit to exit");
+
+    Repl::new().run(io::stdin().lock(), io::stdout().lock())
+}
@@ -0,0 +1,181 @@
+// A runtime calculator, grown from the `calculate!` DSL in `calculator_dsl`.
+//
+// Source text goes through three stages:
+//
+//     "let x = 2 * (3 + 4)"
+//      -> lexer:  [Let, Ident("x"), Equals, Int(2), Star, LeftParen, ...]
+//      -> parser: (let x (* 2 (+ 3 4)))
+//      -> eval:   Int(14), with `x` bound to 14
+//
+// Integers are `i64`, and stay integers until they're combined with a float.
+// Statements are separated by `;`, and the value of the last statement is the
+// result.
+use std::error;
+use std::fmt;
+
+mod ast;
+mod eval;
+mod lexer;
+mod parser;
+pub mod repl;
+
+pub use ast::{BinaryOp, Expr, ExprKind, Stmt, UnaryOp};
+pub use eval::{Calculator, Value};
+
+// a range of bytes in the source text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Span {
+        Span { start, end }
+    }
+
+    // the smallest span covering both spans
+    pub fn to(self, other: Span) -> Span {
+        Span::new(self.start.min(other.start), self.end.max(other.end))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorKind {
+    UnexpectedChar(char),
+    InvalidNumber(String),
+    UnexpectedToken {
+        found: String,
+        expected: &'static str,
+    },
+    UnexpectedEnd {
+        expected: &'static str,
+    },
+    UnknownVariable(String),
+    DivisionByZero,
+    Overflow,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error {
+    pub kind: ErrorKind,
+    // where in the source the error occurred
+    pub span: Span,
+}
+
+impl Error {
+    pub fn new(kind: ErrorKind, span: Span) -> Error {
+        Error { kind, span }
+    }
+
+    // the line of `source` the error is on, with a caret under each character
+    // of the offending text:
+    //
+    //   1 / (r - 2)
+    //       ^^^^^^^
+    pub fn highlight(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let line_start = source[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[start..]
+            .find('\n')
+            .map_or(source.len(), |i| start + i);
+        let end = self.span.end.clamp(start, line_end);
+        let column = source[line_start..start].chars().count();
+        // errors at the end of the input have nothing to underline, so point
+        // just past the last character
+        let width = source[start..end].chars().count().max(1);
+
+        format!(
+            "  {}\n  {}{}",
+            &source[line_start..line_end],
+            " ".repeat(column),
+            "^".repeat(width)
+        )
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ErrorKind::UnexpectedChar(c) => write!(f, "unexpected character '{c}'"),
+            ErrorKind::InvalidNumber(number) => write!(f, "invalid number '{number}'"),
+            ErrorKind::UnexpectedToken { found, expected } => {
+                write!(f, "expected {expected}, found '{found}'")
+            }
+            ErrorKind::UnexpectedEnd { expected } => {
+                write!(f, "expected {expected}, found end of input")
+            }
+            ErrorKind::UnknownVariable(name) => write!(f, "unknown variable '{name}'"),
+            ErrorKind::DivisionByZero => write!(f, "division by zero"),
+            ErrorKind::Overflow => write!(f, "integer overflow"),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+pub fn parse(source: &str) -> Result<Vec<Stmt>, Error> {
+    let tokens = lexer::tokenize(source)?;
+
+    parser::parse(&tokens, source.len())
+}
+
+// evaluate `source` without any variables defined beforehand
+pub fn eval(source: &str) -> Result<Value, Error> {
+    Calculator::new().eval(source)
+}
+
+// evaluate `source`, printing the source, AST, and result along the way
+pub fn debug(source: &str) -> Result<Value, Error> {
+    println!("debugging expression...");
+    println!("{source}");
+
+    let result = parse(source).and_then(|program| {
+        for stmt in &program {
+            println!("ast: {stmt}");
+        }
+
+        Calculator::new().run(&program)
+    });
+
+    match &result {
+        Ok(value) => println!("= {value}"),
+        Err(e) => println!("error: {e}"),
+    }
+
+    result
+}
+
+// Evaluate an expression at runtime, either from Rust tokens or from a string:
+//
+// ```
+// calculate!(2 + 3 * 4)                  // Ok(Int(14))
+// calculate!(let x = 2.5; x ^ 2)         // Ok(Float(6.25))
+// calculate!("10 / (5 - 5)")             // Err(division by zero)
+// calculate!(debug 2 + 2)                // prints the AST and the result
+// ```
+//
+// Tokens are turned back into source text with `stringify!`, so both forms go
+// through the same parser. A lone literal goes through `concat!`, which gives
+// a string's contents, and a number's digits as written: calling `to_string`
+// on a number would type it as an i32 or f64 first, so `2.0` would come back
+// as `2`.
+#[macro_export]
+macro_rules! calculate {
+    (debug $source: literal) => {
+        $crate::calculator::debug(concat!($source))
+    };
+
+    (debug $($tokens: tt)+) => {
+        $crate::calculator::debug(stringify!($($tokens)+))
+    };
+
+    // a string to parse, or a single number
+    ($source: literal) => {
+        $crate::calculator::eval(concat!($source))
+    };
+
+    ($($tokens: tt)+) => {
+        $crate::calculator::eval(stringify!($($tokens)+))
+    };
+}
@@ -0,0 +1,177 @@
+// walks the tree, keeping track of variables between statements
+use std::collections::BTreeMap;
+use std::fmt;
+
+use super::ast::{BinaryOp, Expr, ExprKind, Stmt, UnaryOp};
+use super::{Error, ErrorKind, Span};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Float(f64),
+}
+
+impl Value {
+    pub fn as_f64(self) -> f64 {
+        match self {
+            Value::Int(n) => n as f64,
+            Value::Float(n) => n,
+        }
+    }
+}
+
+// floats always print with a decimal point, so that they can be told apart
+// from integers
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Int(n) => write!(f, "{n}"),
+            Value::Float(n) => write!(f, "{n:?}"),
+        }
+    }
+}
+
+impl From<i64> for Value {
+    fn from(n: i64) -> Self {
+        Value::Int(n)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(n: f64) -> Self {
+        Value::Float(n)
+    }
+}
+
+// evaluates source text, remembering variables bound with `let` for the next
+// call to `eval`
+#[derive(Debug, Clone, Default)]
+pub struct Calculator {
+    vars: BTreeMap<String, Value>,
+}
+
+impl Calculator {
+    pub fn new() -> Calculator {
+        Calculator::default()
+    }
+
+    pub fn eval(&mut self, source: &str) -> Result<Value, Error> {
+        let program = super::parse(source)?;
+
+        self.run(&program)
+    }
+
+    // evaluate every statement in order, returning the value of the last one
+    pub fn run(&mut self, program: &[Stmt]) -> Result<Value, Error> {
+        let mut result = Value::Int(0);
+
+        for stmt in program {
+            result = match stmt {
+                Stmt::Let(name, expr) => {
+                    let value = self.expression(expr)?;
+
+                    self.vars.insert(name.clone(), value);
+
+                    value
+                }
+                Stmt::Expr(expr) => self.expression(expr)?,
+            };
+        }
+
+        Ok(result)
+    }
+
+    pub fn get(&self, name: &str) -> Option<Value> {
+        self.vars.get(name).copied()
+    }
+
+    pub fn set(&mut self, name: &str, value: impl Into<Value>) {
+        self.vars.insert(name.to_string(), value.into());
+    }
+
+    // every variable, sorted by name
+    pub fn vars(&self) -> impl Iterator<Item = (&str, Value)> {
+        self.vars
+            .iter()
+            .map(|(name, value)| (name.as_str(), *value))
+    }
+
+    pub fn clear(&mut self) {
+        self.vars.clear();
+    }
+
+    fn expression(&self, expr: &Expr) -> Result<Value, Error> {
+        match &expr.kind {
+            ExprKind::Int(n) => Ok(Value::Int(*n)),
+            ExprKind::Float(n) => Ok(Value::Float(*n)),
+            ExprKind::Var(name) => self.get(name).ok_or(Error::new(
+                ErrorKind::UnknownVariable(name.clone()),
+                expr.span,
+            )),
+            ExprKind::Unary(op, operand) => {
+                let value = self.expression(operand)?;
+
+                match (op, value) {
+                    (UnaryOp::Plus, _) => Ok(value),
+                    (UnaryOp::Minus, Value::Int(n)) => n
+                        .checked_neg()
+                        .map(Value::Int)
+                        .ok_or(Error::new(ErrorKind::Overflow, expr.span)),
+                    (UnaryOp::Minus, Value::Float(n)) => Ok(Value::Float(-n)),
+                }
+            }
+            ExprKind::Binary(op, lhs, rhs) => {
+                let lhs_value = self.expression(lhs)?;
+                let rhs_value = self.expression(rhs)?;
+
+                binary(*op, lhs_value, rhs_value, expr.span, rhs.span)
+            }
+        }
+    }
+}
+
+fn binary(
+    op: BinaryOp,
+    lhs: Value,
+    rhs: Value,
+    span: Span,
+    rhs_span: Span,
+) -> Result<Value, Error> {
+    let overflow = || Error::new(ErrorKind::Overflow, span);
+
+    // point at the divisor, rather than the whole expression
+    if matches!(op, BinaryOp::Div | BinaryOp::Rem) && rhs.as_f64() == 0.0 {
+        return Err(Error::new(ErrorKind::DivisionByZero, rhs_span));
+    }
+
+    match (lhs, rhs) {
+        (Value::Int(x), Value::Int(y)) => {
+            let result = match op {
+                BinaryOp::Add => x.checked_add(y),
+                BinaryOp::Sub => x.checked_sub(y),
+                BinaryOp::Mul => x.checked_mul(y),
+                // like Rust, integer division rounds towards zero
+                BinaryOp::Div => x.checked_div(y),
+                BinaryOp::Rem => x.checked_rem(y),
+                // a negative power of an integer is a fraction
+                BinaryOp::Pow if y < 0 => return Ok(Value::Float((x as f64).powf(y as f64))),
+                BinaryOp::Pow => u32::try_from(y).ok().and_then(|y| x.checked_pow(y)),
+            };
+
+            result.map(Value::Int).ok_or_else(overflow)
+        }
+        _ => {
+            let (x, y) = (lhs.as_f64(), rhs.as_f64());
+            let result = match op {
+                BinaryOp::Add => x + y,
+                BinaryOp::Sub => x - y,
+                BinaryOp::Mul => x * y,
+                BinaryOp::Div => x / y,
+                BinaryOp::Rem => x % y,
+                BinaryOp::Pow => x.powf(y),
+            };
+
+            Ok(Value::Float(result))
+        }
+    }
+}
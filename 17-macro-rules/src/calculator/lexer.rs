@@ -0,0 +1,125 @@
+// turns source text into tokens
+use super::{Error, ErrorKind, Span};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    Int(i64),
+    Float(f64),
+    Ident(String),
+    Let,
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    Caret,
+    Equals,
+    LeftParen,
+    RightParen,
+    Semicolon,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub span: Span,
+}
+
+pub fn tokenize(source: &str) -> Result<Vec<Token>, Error> {
+    let mut tokens = vec![];
+    let mut chars = source.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        let single = match c {
+            '+' => Some(TokenKind::Plus),
+            '-' => Some(TokenKind::Minus),
+            '*' => Some(TokenKind::Star),
+            '/' => Some(TokenKind::Slash),
+            '%' => Some(TokenKind::Percent),
+            '^' => Some(TokenKind::Caret),
+            '=' => Some(TokenKind::Equals),
+            '(' => Some(TokenKind::LeftParen),
+            ')' => Some(TokenKind::RightParen),
+            ';' => Some(TokenKind::Semicolon),
+            _ => None,
+        };
+
+        if let Some(kind) = single {
+            chars.next();
+            tokens.push(Token {
+                kind,
+                span: Span::new(start, start + 1),
+            });
+            continue;
+        }
+
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        // consume characters while `predicate` holds, returning the end of the
+        // consumed text
+        let mut take_while = |predicate: &dyn Fn(char, Option<char>) -> bool| {
+            let mut end = start;
+            let mut previous = None;
+
+            while let Some(&(i, c)) = chars.peek() {
+                if !predicate(c, previous) {
+                    break;
+                }
+
+                previous = Some(c);
+                end = i + c.len_utf8();
+                chars.next();
+            }
+
+            end
+        };
+
+        let (kind, end) = if c.is_ascii_digit() || c == '.' {
+            // digits, `_` separators, a decimal point, and an exponent. A sign
+            // is only part of the number directly after the `e`
+            let end = take_while(&|c, previous| {
+                c.is_ascii_alphanumeric()
+                    || c == '_'
+                    || c == '.'
+                    || (matches!(c, '+' | '-') && matches!(previous, Some('e' | 'E')))
+            });
+
+            (number(&source[start..end], Span::new(start, end))?, end)
+        } else if c.is_alphabetic() || c == '_' {
+            let end = take_while(&|c, _| c.is_alphanumeric() || c == '_');
+
+            let kind = match &source[start..end] {
+                "let" => TokenKind::Let,
+                ident => TokenKind::Ident(ident.to_string()),
+            };
+
+            (kind, end)
+        } else {
+            return Err(Error::new(
+                ErrorKind::UnexpectedChar(c),
+                Span::new(start, start + c.len_utf8()),
+            ));
+        };
+
+        tokens.push(Token {
+            kind,
+            span: Span::new(start, end),
+        });
+    }
+
+    Ok(tokens)
+}
+
+fn number(text: &str, span: Span) -> Result<TokenKind, Error> {
+    let digits = text.replace('_', "");
+    let invalid = || Error::new(ErrorKind::InvalidNumber(text.to_string()), span);
+
+    if digits.contains(['.', 'e', 'E']) {
+        digits.parse().map(TokenKind::Float).map_err(|_| invalid())
+    } else {
+        digits.parse().map(TokenKind::Int).map_err(|_| invalid())
+    }
+}
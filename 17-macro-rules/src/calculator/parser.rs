@@ -0,0 +1,202 @@
+// turns tokens into statements, using precedence climbing for binary operators
+use super::ast::{BinaryOp, Expr, ExprKind, Stmt, UnaryOp};
+use super::lexer::{Token, TokenKind};
+use super::{Error, ErrorKind, Span};
+
+#[derive(PartialEq)]
+enum Associativity {
+    Left,
+    Right,
+}
+
+// higher binds tighter
+fn binary_op(kind: &TokenKind) -> Option<(BinaryOp, u8, Associativity)> {
+    match kind {
+        TokenKind::Plus => Some((BinaryOp::Add, 1, Associativity::Left)),
+        TokenKind::Minus => Some((BinaryOp::Sub, 1, Associativity::Left)),
+        TokenKind::Star => Some((BinaryOp::Mul, 2, Associativity::Left)),
+        TokenKind::Slash => Some((BinaryOp::Div, 2, Associativity::Left)),
+        TokenKind::Percent => Some((BinaryOp::Rem, 2, Associativity::Left)),
+        TokenKind::Caret => Some((BinaryOp::Pow, 4, Associativity::Right)),
+        _ => None,
+    }
+}
+
+// unary operators bind tighter than `*`, but looser than `^`, so that `-2 ^ 2`
+// is -4
+const UNARY_PRECEDENCE: u8 = 3;
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    position: usize,
+    // where errors at the end of the input point
+    end: Span,
+}
+
+pub fn parse(tokens: &[Token], source_len: usize) -> Result<Vec<Stmt>, Error> {
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+        end: Span::new(source_len, source_len + 1),
+    };
+    let mut program = vec![parser.statement()?];
+
+    while parser.peek().is_some() {
+        parser.expect(TokenKind::Semicolon, "';' or an operator")?;
+
+        // allow a trailing `;`
+        if parser.peek().is_some() {
+            program.push(parser.statement()?);
+        }
+    }
+
+    Ok(program)
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn advance(&mut self) -> Result<&Token, Error> {
+        self.peek_or_end("an expression")?;
+
+        self.position += 1;
+
+        Ok(&self.tokens[self.position - 1])
+    }
+
+    fn peek_or_end(&self, expected: &'static str) -> Result<&Token, Error> {
+        self.peek()
+            .ok_or(Error::new(ErrorKind::UnexpectedEnd { expected }, self.end))
+    }
+
+    fn expect(&mut self, kind: TokenKind, expected: &'static str) -> Result<Span, Error> {
+        let token = self.peek_or_end(expected)?;
+
+        if token.kind == kind {
+            let span = token.span;
+
+            self.position += 1;
+
+            Ok(span)
+        } else {
+            Err(unexpected(token, expected))
+        }
+    }
+
+    fn statement(&mut self) -> Result<Stmt, Error> {
+        if self.peek_or_end("an expression")?.kind != TokenKind::Let {
+            return self.expression(0).map(Stmt::Expr);
+        }
+
+        self.position += 1;
+
+        let token = self.peek_or_end("a variable name")?;
+        let name = match &token.kind {
+            TokenKind::Ident(name) => name.clone(),
+            _ => return Err(unexpected(token, "a variable name")),
+        };
+
+        self.position += 1;
+        self.expect(TokenKind::Equals, "'='")?;
+
+        Ok(Stmt::Let(name, self.expression(0)?))
+    }
+
+    // parse an expression made of operators that bind at least as tightly as
+    // `min_precedence`
+    fn expression(&mut self, min_precedence: u8) -> Result<Expr, Error> {
+        let mut lhs = self.unary()?;
+
+        while let Some((op, precedence, associativity)) =
+            self.peek().and_then(|token| binary_op(&token.kind))
+        {
+            if precedence < min_precedence {
+                break;
+            }
+
+            self.position += 1;
+
+            // a left-associative operator stops the right-hand side at the
+            // next operator of the same precedence, so 1 - 2 - 3 is
+            // (1 - 2) - 3. A right-associative one doesn't, so 2 ^ 3 ^ 2 is
+            // 2 ^ (3 ^ 2)
+            let next_precedence = match associativity {
+                Associativity::Left => precedence + 1,
+                Associativity::Right => precedence,
+            };
+            let rhs = self.expression(next_precedence)?;
+            let span = lhs.span.to(rhs.span);
+
+            lhs = Expr {
+                kind: ExprKind::Binary(op, Box::new(lhs), Box::new(rhs)),
+                span,
+            };
+        }
+
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<Expr, Error> {
+        let token = self.peek_or_end("an expression")?;
+        let op = match token.kind {
+            TokenKind::Plus => UnaryOp::Plus,
+            TokenKind::Minus => UnaryOp::Minus,
+            _ => return self.primary(),
+        };
+        let start = token.span;
+
+        self.position += 1;
+
+        let expr = self.expression(UNARY_PRECEDENCE)?;
+
+        Ok(Expr {
+            span: start.to(expr.span),
+            kind: ExprKind::Unary(op, Box::new(expr)),
+        })
+    }
+
+    fn primary(&mut self) -> Result<Expr, Error> {
+        let token = self.advance()?;
+        let span = token.span;
+        let kind = match &token.kind {
+            TokenKind::Int(n) => ExprKind::Int(*n),
+            TokenKind::Float(n) => ExprKind::Float(*n),
+            TokenKind::Ident(name) => ExprKind::Var(name.clone()),
+            TokenKind::LeftParen => {
+                let expr = self.expression(0)?;
+                let end = self.expect(TokenKind::RightParen, "')' or an operator")?;
+
+                return Ok(Expr {
+                    span: span.to(end),
+                    ..expr
+                });
+            }
+            _ => return Err(unexpected(token, "an expression")),
+        };
+
+        Ok(Expr { kind, span })
+    }
+}
+
+fn unexpected(token: &Token, expected: &'static str) -> Error {
+    let found = match &token.kind {
+        TokenKind::Int(n) => n.to_string(),
+        TokenKind::Float(n) => format!("{n:?}"),
+        TokenKind::Ident(name) => name.clone(),
+        TokenKind::Let => "let".to_string(),
+        TokenKind::Plus => "+".to_string(),
+        TokenKind::Minus => "-".to_string(),
+        TokenKind::Star => "*".to_string(),
+        TokenKind::Slash => "/".to_string(),
+        TokenKind::Percent => "%".to_string(),
+        TokenKind::Caret => "^".to_string(),
+        TokenKind::Equals => "=".to_string(),
+        TokenKind::LeftParen => "(".to_string(),
+        TokenKind::RightParen => ")".to_string(),
+        TokenKind::Semicolon => ";".to_string(),
+    };
+
+    Error::new(ErrorKind::UnexpectedToken { found, expected }, token.span)
+}
@@ -0,0 +1,163 @@
+// A read-eval-print loop for the calculator, following the pattern of
+// `index_error_inputter` in 02-primitives: read a line, and report bad input
+// instead of giving up.
+//
+//     > let r = 2
+//     = 2
+//     > 3.14 * r ^
+//     . 2
+//     = 12.56
+//     > 1 / (r - 2)
+//     error: division by zero
+//       1 / (r - 2)
+//           ^^^^^^^
+//
+// An entry continues onto the next line while it is incomplete, e.g. after a
+// trailing operator or an unclosed `(`. An empty line ends the entry early.
+use std::io::{self, BufRead, Write};
+
+use super::{Calculator, Error, ErrorKind};
+
+const HELP: &str = "\
+commands:
+    :vars       list variables
+    :history    list previous entries
+    :clear      remove all variables
+    :help       show this message
+    :quit       exit (as does Ctrl-D)
+    !!          repeat the previous entry
+    !<n>        repeat entry <n> from :history";
+
+#[derive(Debug, Default)]
+pub struct Repl {
+    calculator: Calculator,
+    history: Vec<String>,
+}
+
+// what to do after handling a line
+enum Flow {
+    Continue,
+    Quit,
+}
+
+impl Repl {
+    pub fn new() -> Repl {
+        Repl::default()
+    }
+
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    pub fn calculator(&self) -> &Calculator {
+        &self.calculator
+    }
+
+    // read entries from `input` until `:quit` or the end of input
+    pub fn run(&mut self, mut input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+        let mut entry = String::new();
+
+        loop {
+            write!(output, "{}", if entry.is_empty() { "> " } else { ". " })?;
+            output.flush()?;
+
+            let mut line = String::new();
+
+            if input.read_line(&mut line)? == 0 {
+                writeln!(output)?;
+
+                return Ok(());
+            }
+
+            let line = line.trim_end_matches(['\n', '\r']);
+
+            if entry.is_empty() {
+                match self.command(line.trim(), &mut output)? {
+                    Some(Flow::Quit) => return Ok(()),
+                    Some(Flow::Continue) => continue,
+                    None => entry.push_str(line),
+                }
+            } else if line.trim().is_empty() {
+                // evaluate the incomplete entry, to report what's missing
+                self.eval(&entry, &mut output)?;
+                entry.clear();
+                continue;
+            } else {
+                entry.push('\n');
+                entry.push_str(line);
+            }
+
+            if !is_incomplete(&entry) {
+                self.eval(&entry, &mut output)?;
+                entry.clear();
+            }
+        }
+    }
+
+    // handle commands and history expansion. Returns `None` if `line` is an
+    // expression to evaluate
+    fn command(&mut self, line: &str, output: &mut impl Write) -> io::Result<Option<Flow>> {
+        match line {
+            "" => {}
+            ":quit" | ":q" => return Ok(Some(Flow::Quit)),
+            ":help" => writeln!(output, "{HELP}")?,
+            ":clear" => {
+                self.calculator.clear();
+                writeln!(output, "cleared all variables")?;
+            }
+            ":vars" => {
+                for (name, value) in self.calculator.vars() {
+                    writeln!(output, "{name} = {value}")?;
+                }
+            }
+            ":history" => {
+                for (i, entry) in self.history.iter().enumerate() {
+                    writeln!(output, "{:>3}  {}", i + 1, entry.replace('\n', "\n     "))?;
+                }
+            }
+            _ if line.starts_with(':') => {
+                writeln!(output, "error: unknown command '{line}', try :help")?;
+            }
+            _ if line.starts_with('!') => match self.recall(&line[1..]) {
+                Some(entry) => {
+                    writeln!(output, "{entry}")?;
+                    self.eval(&entry, output)?;
+                }
+                None => writeln!(output, "error: no history entry '{line}'")?,
+            },
+            _ => return Ok(None),
+        }
+
+        Ok(Some(Flow::Continue))
+    }
+
+    // `!!` is the last entry, and `!n` is the nth
+    fn recall(&self, which: &str) -> Option<String> {
+        let index = match which {
+            "!" => self.history.len().checked_sub(1)?,
+            n => n.parse::<usize>().ok()?.checked_sub(1)?,
+        };
+
+        self.history.get(index).cloned()
+    }
+
+    fn eval(&mut self, entry: &str, output: &mut impl Write) -> io::Result<()> {
+        self.history.push(entry.to_string());
+
+        match self.calculator.eval(entry) {
+            Ok(value) => writeln!(output, "= {value}"),
+            Err(e) => writeln!(output, "error: {e}\n{}", e.highlight(entry)),
+        }
+    }
+}
+
+// whether more input could turn `entry` into a valid program
+fn is_incomplete(entry: &str) -> bool {
+    matches!(
+        super::parse(entry),
+        Err(Error {
+            kind: ErrorKind::UnexpectedEnd { .. },
+            ..
+        })
+    )
+}
@@ -0,0 +1,197 @@
+use rbe_registry::Chapter;
+
+pub mod calculator;
+
+macro_rules! say_hello {
+    // () means 'take no arguments'
+    () => {
+        println!("hello!\n")
+    };
+}
+
+fn say_hello() {
+    say_hello!();
+}
+
+fn macros_inside_functions() {
+    macro_rules! inner {
+        () => {
+            println!("inner called!")
+        };
+    }
+
+    inner!();
+    println!()
+}
+
+fn function_factory() {
+    macro_rules! create_function {
+        ($name: ident) => {
+            fn $name() {
+                println!("called function {}()", stringify!($name))
+            }
+        };
+    }
+
+    create_function!(foo_a);
+    create_function!(foo_b);
+
+    foo_a();
+    foo_b();
+
+    println!()
+}
+
+fn expression_evaluator() {
+    macro_rules! eval {
+        ($expression: expr) => {
+            println!(
+                "evaluating expression:\n{}\n=> {}\n",
+                stringify!($expression),
+                $expression
+            )
+        };
+    }
+
+    eval!(2 + 2);
+
+    // a block is an expression
+    eval!({
+        let x = String::from("foo");
+
+        x + "bar"
+    });
+}
+
+#[allow(clippy::eq_op, clippy::identity_op)]
+fn overload() {
+    macro_rules! and_or {
+        // '; or ' is a template
+        ($left: expr; or $right: expr) => {
+            println!(
+                "{} or {}\n= {}\n",
+                stringify!($left),
+                stringify!($right),
+                $left || $right
+            )
+        }; // arm ends with a semi-colon
+
+        // '; and ' is a template
+        ($left: expr; and $right: expr) => {
+            println!(
+                "{} and {}\n= {}\n",
+                stringify!($left),
+                stringify!($right),
+                $left && $right
+            )
+        };
+
+        ($left: expr) => {
+            println!("only left given: {}\n= {}", stringify!($left), !!$left)
+        };
+    }
+
+    and_or!(2 * 1 == 2_i32; or 1 + 2 == 3_i32);
+    and_or!(true; and false);
+    and_or!("a" == "A");
+    println!()
+}
+
+fn repetition() {
+    macro_rules! zero_or_more {
+        () => {
+            println!("no arguments\n")
+        };
+
+        ($x: expr) => {
+            let xs = Vec::from([$x]);
+
+            println!("many arguments: {:?}", xs)
+        };
+
+        ($x: expr, $($xs:expr),*) => {
+            let xs = Vec::from([$x, $($xs),*]);
+
+            println!("many arguments: {:?}", xs)
+        };
+    }
+
+    macro_rules! one_or_more {
+        ($x: expr) => {
+            let xs = Vec::from([$x]);
+
+            println!("one argument: {:?}", xs)
+        };
+
+        ($x: expr, $($xs: expr), +) => {
+            let xs = Vec::from([$x, $($xs),+]);
+
+            println!("many arguments: {:?}", xs)
+        };
+    }
+
+    zero_or_more!();
+    zero_or_more!(1);
+    zero_or_more!(1, 2, 3);
+    println!();
+
+    one_or_more!(1);
+    one_or_more!(1, 2, 3);
+    println!();
+}
+
+fn recursion() {
+    macro_rules! min {
+        ($x: expr) => ( $x );
+
+        ($x: expr, $($xs: expr),+) => {
+            core::cmp::min($x, min!($($xs),+))
+        }
+    }
+
+    println!("min: {:?}", min!(1));
+    println!("min: {:?}", min!(3, 6, 2, 37, 35));
+    println!()
+}
+
+fn calculator_dsl() {
+    // `calculate!` is defined in `calculator`, and evaluates expressions at
+    // runtime instead of compiling them as Rust
+    println!["without debugging: {:?}\n", calculate! {2 + 2}];
+
+    let _ = calculate!(debug 2 + 2);
+    println!();
+
+    let _ = calculate!(debug let radius = 1.5; 3.14 * radius ^ 2);
+    println!();
+
+    // a string is parsed as-is, so it can use syntax that isn't valid Rust
+    match calculate!("10 / (5 - 5)") {
+        Ok(value) => println!("10 / (5 - 5) = {value}"),
+        Err(e) => println!("10 / (5 - 5) failed: {e}"),
+    }
+
+    // a `Calculator` remembers variables between calls
+    let mut calculator = calculator::Calculator::new();
+
+    for line in ["let x = 7", "let y = x / 2", "x % y + y ^ 2"] {
+        match calculator.eval(line) {
+            Ok(value) => println!("{line} => {value}"),
+            Err(e) => println!("{line} => error: {e}"),
+        }
+    }
+
+    println!();
+}
+
+pub const CHAPTER: Chapter = rbe_registry::chapter! {
+    number: 17,
+    name: "macro_rules",
+    examples: {
+        _: [say_hello, macros_inside_functions],
+        "designators": [function_factory, expression_evaluator],
+        "overloads": [overload],
+        "repetition": [repetition, recursion],
+        "DSL": [calculator_dsl],
+    },
+};
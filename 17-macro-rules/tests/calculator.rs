@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod calculator_tests {
+    use macro_rules::calculate;
+    use macro_rules::calculator::{self, Calculator, ErrorKind, Span, Value};
+
+    fn ast(source: &str) -> String {
+        calculator::parse(source)
+            .unwrap()
+            .iter()
+            .map(|stmt| stmt.to_string())
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+
+    fn error(source: &str) -> (ErrorKind, Span) {
+        let e = calculator::eval(source).unwrap_err();
+
+        (e.kind, e.span)
+    }
+
+    #[test]
+    fn precedence_and_associativity() {
+        assert_eq!(ast("1 + 2 * 3"), "(+ 1 (* 2 3))");
+        assert_eq!(ast("(1 + 2) * 3"), "(* (+ 1 2) 3)");
+        assert_eq!(ast("1 - 2 - 3"), "(- (- 1 2) 3)");
+        assert_eq!(ast("2 ^ 3 ^ 2"), "(^ 2 (^ 3 2))");
+        assert_eq!(ast("-2 ^ 2"), "(- (^ 2 2))");
+        assert_eq!(ast("-2 * 3"), "(* (- 2) 3)");
+        assert_eq!(ast("let x = 1e3 % 7"), "(let x (% 1000.0 7))");
+    }
+
+    #[test]
+    fn integers_and_floats() {
+        assert_eq!(calculator::eval("1 + 2 * 3"), Ok(Value::Int(7)));
+        assert_eq!(calculator::eval("7 / 2"), Ok(Value::Int(3)));
+        assert_eq!(calculator::eval("7 / 2.0"), Ok(Value::Float(3.5)));
+        assert_eq!(calculator::eval("-7 % 3"), Ok(Value::Int(-1)));
+        assert_eq!(calculator::eval("2 ^ 10"), Ok(Value::Int(1024)));
+        assert_eq!(calculator::eval("2 ^ -1"), Ok(Value::Float(0.5)));
+        assert_eq!(calculator::eval("1_000 * 1.5e-3"), Ok(Value::Float(1.5)));
+        assert_eq!(calculator::eval("-2 ^ 2"), Ok(Value::Int(-4)));
+        assert_eq!(calculator::eval("42").unwrap().to_string(), "42");
+        assert_eq!(calculator::eval("42.0").unwrap().to_string(), "42.0");
+    }
+
+    #[test]
+    fn variables() {
+        let mut calculator = Calculator::new();
+
+        assert_eq!(calculator.eval("let x = 4"), Ok(Value::Int(4)));
+        assert_eq!(
+            calculator.eval("let y = x * 2.5; y + x"),
+            Ok(Value::Float(14.0))
+        );
+        assert_eq!(calculator.get("y"), Some(Value::Float(10.0)));
+
+        calculator.set("z", 1);
+
+        let vars: Vec<_> = calculator.vars().map(|(name, _)| name).collect();
+
+        assert_eq!(vars, ["x", "y", "z"]);
+
+        calculator.clear();
+
+        assert_eq!(
+            calculator.eval("x").unwrap_err().kind,
+            ErrorKind::UnknownVariable("x".to_string())
+        );
+    }
+
+    #[test]
+    fn errors_point_at_the_problem() {
+        assert_eq!(
+            error("1 + #"),
+            (ErrorKind::UnexpectedChar('#'), Span::new(4, 5))
+        );
+        assert_eq!(
+            error("1 +"),
+            (
+                ErrorKind::UnexpectedEnd {
+                    expected: "an expression"
+                },
+                Span::new(3, 4)
+            )
+        );
+        assert_eq!(
+            error("(1 + 2"),
+            (
+                ErrorKind::UnexpectedEnd {
+                    expected: "')' or an operator"
+                },
+                Span::new(6, 7)
+            )
+        );
+        assert_eq!(
+            error("1 2"),
+            (
+                ErrorKind::UnexpectedToken {
+                    found: "2".to_string(),
+                    expected: "';' or an operator"
+                },
+                Span::new(2, 3)
+            )
+        );
+        assert_eq!(
+            error("10 / (5 - 5)"),
+            (ErrorKind::DivisionByZero, Span::new(5, 12))
+        );
+        assert_eq!(
+            error("1 + foo"),
+            (
+                ErrorKind::UnknownVariable("foo".to_string()),
+                Span::new(4, 7)
+            )
+        );
+        assert_eq!(error("2 ^ 64").0, ErrorKind::Overflow);
+        assert_eq!(
+            error("1.2.3").0,
+            ErrorKind::InvalidNumber("1.2.3".to_string())
+        );
+        assert_eq!(error("let = 2").1, Span::new(4, 5));
+    }
+
+    #[test]
+    fn macro_accepts_tokens_and_strings() {
+        assert_eq!(calculate!(2 + 3 * 4), Ok(Value::Int(14)));
+        assert_eq!(calculate!(let x = 2.5; x ^ 2), Ok(Value::Float(6.25)));
+        assert_eq!(calculate!("2 ^ 3 ^ 2"), Ok(Value::Int(512)));
+        assert_eq!(calculate!(42), Ok(Value::Int(42)));
+        assert_eq!(calculate!(-1.5), Ok(Value::Float(-1.5)));
+        // literals keep their type, and aren't limited to an i32
+        assert_eq!(calculate!(2.0), Ok(Value::Float(2.0)));
+        assert_eq!(calculate!(1.5e3), Ok(Value::Float(1500.0)));
+        assert_eq!(calculate!(3000000000), Ok(Value::Int(3_000_000_000)));
+        assert_eq!(calculate!(debug 2.0), Ok(Value::Float(2.0)));
+        assert_eq!(calculate!(debug(1 + 1) * 2), Ok(Value::Int(4)));
+        assert!(calculate!("1 / 0").is_err());
+    }
+}
@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod repl_tests {
+    use macro_rules::calculator::repl::Repl;
+    use macro_rules::calculator::Value;
+
+    // run `input` through a new REPL, returning it and everything it printed
+    fn transcript(input: &str) -> (Repl, String) {
+        let mut repl = Repl::new();
+        let mut output = vec![];
+
+        repl.run(input.as_bytes(), &mut output).unwrap();
+
+        (repl, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn evaluates_each_line() {
+        let (repl, output) = transcript("let x = 6\nx * 7\n");
+
+        assert_eq!(output, "> = 6\n> = 42\n> \n");
+        assert_eq!(repl.calculator().get("x"), Some(Value::Int(6)));
+    }
+
+    #[test]
+    fn incomplete_entries_continue_onto_the_next_line() {
+        let (repl, output) = transcript("(1 +\n2) *\n3\n");
+
+        assert_eq!(output, "> . . = 9\n> \n");
+        assert_eq!(repl.history(), ["(1 +\n2) *\n3"]);
+    }
+
+    #[test]
+    fn empty_line_ends_an_incomplete_entry() {
+        let (_, output) = transcript("1 +\n\n");
+
+        assert_eq!(
+            output,
+            "> . error: expected an expression, found end of input\n  1 +\n     ^\n> \n"
+        );
+    }
+
+    #[test]
+    fn errors_point_at_the_offending_column() {
+        let (_, output) = transcript("1 / (2 - 2)\n1 + y\n");
+
+        assert_eq!(
+            output,
+            "\
+> error: division by zero
+  1 / (2 - 2)
+      ^^^^^^^
+> error: unknown variable 'y'
+  1 + y
+      ^
+> \n"
+        );
+    }
+
+    #[test]
+    fn errors_on_later_lines_of_an_entry() {
+        let (_, output) = transcript("(1 +\n  2 $)\n");
+
+        assert_eq!(
+            output,
+            "> . error: unexpected character '$'\n    2 $)\n      ^\n> \n"
+        );
+    }
+
+    #[test]
+    fn history() {
+        let (repl, output) = transcript("1 + 1\n!!\n2 * 2\n!1\n!7\n:history\n");
+
+        assert_eq!(
+            output,
+            "\
+> = 2
+> 1 + 1
+= 2
+> = 4
+> 1 + 1
+= 2
+> error: no history entry '!7'
+>   1  1 + 1
+  2  1 + 1
+  3  2 * 2
+  4  1 + 1
+> \n"
+        );
+        assert_eq!(repl.history().len(), 4);
+    }
+
+    #[test]
+    fn commands() {
+        let (repl, output) = transcript("let b = 2\nlet a = 1.5\n:vars\n:clear\n:vars\n:nope\n");
+
+        assert_eq!(
+            output,
+            "\
+> = 2
+> = 1.5
+> a = 1.5
+b = 2
+> cleared all variables
+> > error: unknown command ':nope', try :help
+> \n"
+        );
+        assert_eq!(repl.calculator().vars().count(), 0);
+    }
+
+    #[test]
+    fn quit_stops_reading() {
+        let (repl, output) = transcript("1\n:quit\n2\n");
+
+        assert_eq!(output, "> = 1\n> ");
+        assert_eq!(repl.history(), ["1"]);
+    }
+}
@@ -0,0 +1,269 @@
+// Checked maths, grown from the `mod checked` in `result_example`.
+//
+// Float functions return an `Op` instead of a bare `f64`, so that more checked
+// functions can be chained onto the result without falling back to the
+// unchecked `f64` methods:
+//
+//     fn op(x: f64, y: f64) -> MathResult {
+//         checked::div(x, y)?.sqrt()?.ln()
+//     }
+//
+// Integer functions work for every integer width, and return the same
+// `MathError` on overflow.
+use std::error;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MathError {
+    DivisionByZero,
+    NegativeSquareRoot,
+    NonPositiveLogarithm,
+    // log_base with a base that is negative, 0, or 1
+    InvalidLogarithmBase,
+    // asin or acos of a value outside of -1..=1
+    OutOfDomain,
+    // e.g. (-8.0).powf(1.0 / 3.0), which has no real result
+    FractionalPowerOfNegative,
+    NotANumber,
+    Overflow,
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            MathError::DivisionByZero => "division by zero",
+            MathError::NegativeSquareRoot => "square root of a negative number",
+            MathError::NonPositiveLogarithm => "logarithm of a non-positive number",
+            MathError::InvalidLogarithmBase => "logarithm base must be positive, and not 1",
+            MathError::OutOfDomain => "value must be between -1 and 1",
+            MathError::FractionalPowerOfNegative => "fractional power of a negative number",
+            MathError::NotANumber => "value is NaN",
+            MathError::Overflow => "arithmetic overflow",
+        };
+
+        write!(f, "{description}")
+    }
+}
+
+impl error::Error for MathError {}
+
+pub type MathResult = Result<Op, MathError>;
+
+// the result of a checked float operation, which further checked operations
+// can be chained onto
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub struct Op(f64);
+
+impl Op {
+    pub fn new(x: f64) -> Op {
+        Op(x)
+    }
+
+    pub fn value(self) -> f64 {
+        self.0
+    }
+
+    // not `std::ops::Div`, since dividing can fail
+    #[allow(clippy::should_implement_trait)]
+    pub fn div(self, y: impl Into<f64>) -> MathResult {
+        div(self, y)
+    }
+
+    pub fn sqrt(self) -> MathResult {
+        sqrt(self)
+    }
+
+    pub fn ln(self) -> MathResult {
+        ln(self)
+    }
+
+    pub fn log_base(self, base: impl Into<f64>) -> MathResult {
+        log_base(self, base)
+    }
+
+    pub fn pow(self, exponent: impl Into<f64>) -> MathResult {
+        pow(self, exponent)
+    }
+
+    pub fn asin(self) -> MathResult {
+        asin(self)
+    }
+
+    pub fn acos(self) -> MathResult {
+        acos(self)
+    }
+}
+
+impl From<f64> for Op {
+    fn from(x: f64) -> Self {
+        Op(x)
+    }
+}
+
+impl From<Op> for f64 {
+    fn from(op: Op) -> Self {
+        op.0
+    }
+}
+
+impl PartialEq<f64> for Op {
+    fn eq(&self, other: &f64) -> bool {
+        self.0 == *other
+    }
+}
+
+// print the value on its own, so `Ok(Op(0.5))` prints as `Ok(0.5)`
+impl fmt::Debug for Op {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+// every float function rejects NaN, which would otherwise slip past the
+// comparisons below
+fn number(x: impl Into<f64>) -> Result<f64, MathError> {
+    let x = x.into();
+
+    if x.is_nan() {
+        Err(MathError::NotANumber)
+    } else {
+        Ok(x)
+    }
+}
+
+pub fn div(x: impl Into<f64>, y: impl Into<f64>) -> MathResult {
+    let (x, y) = (number(x)?, number(y)?);
+
+    if y == 0.0 {
+        return Err(MathError::DivisionByZero);
+    }
+
+    let result = x / y;
+
+    // as with pow, only finite values can overflow
+    if result.is_infinite() && x.is_finite() {
+        Err(MathError::Overflow)
+    } else {
+        Ok(Op(result))
+    }
+}
+
+pub fn sqrt(x: impl Into<f64>) -> MathResult {
+    let x = number(x)?;
+
+    if x < 0.0 {
+        Err(MathError::NegativeSquareRoot)
+    } else {
+        Ok(Op(x.sqrt()))
+    }
+}
+
+pub fn ln(x: impl Into<f64>) -> MathResult {
+    let x = number(x)?;
+
+    if x <= 0.0 {
+        Err(MathError::NonPositiveLogarithm)
+    } else {
+        Ok(Op(x.ln()))
+    }
+}
+
+pub fn log_base(x: impl Into<f64>, base: impl Into<f64>) -> MathResult {
+    let (x, base) = (number(x)?, number(base)?);
+
+    if base <= 0.0 || base == 1.0 {
+        Err(MathError::InvalidLogarithmBase)
+    } else if x <= 0.0 {
+        Err(MathError::NonPositiveLogarithm)
+    } else {
+        Ok(Op(x.log(base)))
+    }
+}
+
+pub fn pow(base: impl Into<f64>, exponent: impl Into<f64>) -> MathResult {
+    let (base, exponent) = (number(base)?, number(exponent)?);
+
+    if base == 0.0 && exponent < 0.0 {
+        return Err(MathError::DivisionByZero);
+    }
+
+    if base < 0.0 && exponent.fract() != 0.0 {
+        return Err(MathError::FractionalPowerOfNegative);
+    }
+
+    let result = base.powf(exponent);
+
+    // only finite values can overflow - infinity to any power is allowed
+    if result.is_infinite() && base.is_finite() && exponent.is_finite() {
+        Err(MathError::Overflow)
+    } else {
+        Ok(Op(result))
+    }
+}
+
+pub fn asin(x: impl Into<f64>) -> MathResult {
+    let x = number(x)?;
+
+    if (-1.0..=1.0).contains(&x) {
+        Ok(Op(x.asin()))
+    } else {
+        Err(MathError::OutOfDomain)
+    }
+}
+
+pub fn acos(x: impl Into<f64>) -> MathResult {
+    let x = number(x)?;
+
+    if (-1.0..=1.0).contains(&x) {
+        Ok(Op(x.acos()))
+    } else {
+        Err(MathError::OutOfDomain)
+    }
+}
+
+// integers that can be added, subtracted, and multiplied without overflowing
+pub trait Integer: Copy {
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($t: ty),+ $(,)?) => {
+        $(
+            impl Integer for $t {
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+            }
+        )+
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+pub fn add<T: Integer>(x: T, y: T) -> Result<T, MathError> {
+    x.checked_add(y).ok_or(MathError::Overflow)
+}
+
+pub fn sub<T: Integer>(x: T, y: T) -> Result<T, MathError> {
+    x.checked_sub(y).ok_or(MathError::Overflow)
+}
+
+pub fn mul<T: Integer>(x: T, y: T) -> Result<T, MathError> {
+    x.checked_mul(y).ok_or(MathError::Overflow)
+}
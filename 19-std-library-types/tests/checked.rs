@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod checked_tests {
+    use std::error::Error;
+    use std_library_types::checked::{self, MathError, MathResult, Op};
+
+    fn op(x: f64, y: f64) -> MathResult {
+        checked::div(x, y)?.sqrt()?.ln()
+    }
+
+    #[test]
+    fn chaining() {
+        assert_eq!(op(8.0, 2.0), Ok(Op::new(2.0_f64.ln())));
+        assert_eq!(op(1.0, 0.0), Err(MathError::DivisionByZero));
+        assert_eq!(op(-1.0, 2.0), Err(MathError::NegativeSquareRoot));
+        assert_eq!(op(0.0, 2.0), Err(MathError::NonPositiveLogarithm));
+        assert_eq!(
+            checked::sqrt(16.0)
+                .and_then(|x| x.log_base(2.0))
+                .map(Op::value),
+            Ok(2.0)
+        );
+    }
+
+    #[test]
+    fn pow() {
+        assert_eq!(checked::pow(2.0, 10.0), Ok(Op::new(1024.0)));
+        assert_eq!(checked::pow(-2.0, 3.0), Ok(Op::new(-8.0)));
+        assert_eq!(
+            checked::pow(-8.0, 1.0 / 3.0),
+            Err(MathError::FractionalPowerOfNegative)
+        );
+        assert_eq!(checked::pow(0.0, -1.0), Err(MathError::DivisionByZero));
+        assert_eq!(checked::pow(10.0, 400.0), Err(MathError::Overflow));
+        assert_eq!(checked::pow(f64::INFINITY, 2.0), Ok(Op::new(f64::INFINITY)));
+    }
+
+    #[test]
+    fn div() {
+        assert_eq!(checked::div(1.0, 4.0), Ok(Op::new(0.25)));
+        assert_eq!(checked::div(1.0, 0.0), Err(MathError::DivisionByZero));
+        assert_eq!(checked::div(f64::MAX, 0.5), Err(MathError::Overflow));
+        assert_eq!(Op::new(-f64::MAX).div(0.5), Err(MathError::Overflow));
+        assert_eq!(checked::div(f64::INFINITY, 2.0), Ok(Op::new(f64::INFINITY)));
+        assert_eq!(checked::div(1.0, f64::INFINITY), Ok(Op::new(0.0)));
+    }
+
+    #[test]
+    fn logarithms() {
+        assert_eq!(checked::log_base(8.0, 2.0).map(Op::value), Ok(3.0));
+        assert!((checked::log_base(1000.0, 10.0).unwrap().value() - 3.0).abs() < 1e-12);
+        assert_eq!(
+            checked::log_base(8.0, 1.0),
+            Err(MathError::InvalidLogarithmBase)
+        );
+        assert_eq!(
+            checked::log_base(8.0, -2.0),
+            Err(MathError::InvalidLogarithmBase)
+        );
+        assert_eq!(
+            checked::log_base(-8.0, 2.0),
+            Err(MathError::NonPositiveLogarithm)
+        );
+        assert_eq!(checked::ln(0.0), Err(MathError::NonPositiveLogarithm));
+    }
+
+    #[test]
+    fn inverse_trig_domain() {
+        assert_eq!(
+            checked::asin(1.0).map(Op::value),
+            Ok(std::f64::consts::FRAC_PI_2)
+        );
+        assert_eq!(checked::acos(1.0).map(Op::value), Ok(0.0));
+        assert_eq!(checked::asin(1.5), Err(MathError::OutOfDomain));
+        assert_eq!(checked::acos(-1.01), Err(MathError::OutOfDomain));
+    }
+
+    #[test]
+    fn nan_is_rejected() {
+        assert_eq!(checked::sqrt(f64::NAN), Err(MathError::NotANumber));
+        assert_eq!(checked::asin(f64::NAN), Err(MathError::NotANumber));
+        assert_eq!(checked::div(1.0, f64::NAN), Err(MathError::NotANumber));
+    }
+
+    #[test]
+    fn integer_overflow() {
+        assert_eq!(checked::add(i8::MAX, 1), Err(MathError::Overflow));
+        assert_eq!(checked::add(250_u8, 5), Ok(255));
+        assert_eq!(checked::sub(0_u32, 1), Err(MathError::Overflow));
+        assert_eq!(checked::sub(i64::MIN, 1), Err(MathError::Overflow));
+        assert_eq!(checked::mul(u128::MAX, 2), Err(MathError::Overflow));
+        assert_eq!(checked::mul(-4_isize, 5), Ok(-20));
+        assert_eq!(checked::add(usize::MAX - 1, 1), Ok(usize::MAX));
+        assert_eq!(checked::mul(i16::MIN, -1), Err(MathError::Overflow));
+    }
+
+    #[test]
+    fn errors_display() {
+        let e: Box<dyn Error> = Box::new(MathError::DivisionByZero);
+
+        assert_eq!(e.to_string(), "division by zero");
+        assert_eq!(MathError::Overflow.to_string(), "arithmetic overflow");
+        assert_eq!(format!("{:?}", checked::div(1.0, 2.0)), "Ok(0.5)");
+    }
+}
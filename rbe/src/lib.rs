@@ -0,0 +1,34 @@
+use rbe_registry::{Chapter, Example};
+
+// every chapter that registers its examples, in book order
+pub const CHAPTERS: &[Chapter] = &[
+    formatted_print::CHAPTER,
+    primitives::CHAPTER,
+    custom_types::CHAPTER,
+    variable_bindings::CHAPTER,
+    types::CHAPTER,
+    conversion::CHAPTER,
+    flow_of_control::CHAPTER,
+    functions::CHAPTER,
+    modules::CHAPTER,
+    generics::CHAPTER,
+    scoping_rules::CHAPTER,
+    traits::CHAPTER,
+    macro_rules::CHAPTER,
+    error_handling::CHAPTER,
+    std_library_types::CHAPTER,
+];
+
+pub fn chapter(needle: &str) -> Option<&'static Chapter> {
+    CHAPTERS.iter().find(|chapter| chapter.matches(needle))
+}
+
+// find an example by its path, e.g. `traits::supertraits`
+pub fn example(path: &str) -> Option<(&'static Chapter, &'static Example)> {
+    let (chapter_name, example_name) = path.split_once("::")?;
+    let chapter = chapter(chapter_name)?;
+
+    chapter
+        .example(example_name)
+        .map(|example| (chapter, example))
+}
@@ -0,0 +1,105 @@
+use std::env;
+use std::process::ExitCode;
+
+use rbe_registry::Chapter;
+
+const USAGE: &str = "usage:
+    rbe list [--chapter <number|name>]
+    rbe run <chapter>::<example>...
+    rbe run --chapter <number|name>";
+
+enum Command {
+    List(Option<String>),
+    Run(Vec<String>),
+    RunChapter(String),
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    match args {
+        [cmd] if cmd == "list" => Ok(Command::List(None)),
+        [cmd, flag, chapter] if cmd == "list" && flag == "--chapter" => {
+            Ok(Command::List(Some(chapter.to_owned())))
+        }
+        [cmd, flag, chapter] if cmd == "run" && flag == "--chapter" => {
+            Ok(Command::RunChapter(chapter.to_owned()))
+        }
+        [cmd, paths @ ..] if cmd == "run" && !paths.is_empty() => {
+            if let Some(flag) = paths.iter().find(|path| path.starts_with('-')) {
+                return Err(format!("unexpected argument '{flag}'"));
+            }
+
+            Ok(Command::Run(paths.to_vec()))
+        }
+        [] => Err("no command given".to_string()),
+        [cmd, ..] => Err(format!("invalid arguments for '{cmd}'")),
+    }
+}
+
+fn find_chapter(needle: &str) -> Result<&'static Chapter, String> {
+    rbe::chapter(needle).ok_or_else(|| format!("no chapter matching '{needle}'"))
+}
+
+fn list(needle: Option<&str>) -> Result<(), String> {
+    let chapters = match needle {
+        Some(needle) => vec![find_chapter(needle)?],
+        None => rbe::CHAPTERS.iter().collect(),
+    };
+
+    let rows: Vec<_> = chapters
+        .into_iter()
+        .flat_map(|chapter| {
+            chapter.examples.iter().map(|example| {
+                let path = format!("{}::{}", chapter.name, example.name);
+
+                (chapter.number, path, example.heading)
+            })
+        })
+        .collect();
+    // pad paths so that headings line up in a column
+    let width = rows
+        .iter()
+        .map(|(_, path, _)| path.len())
+        .max()
+        .unwrap_or(0);
+
+    for (number, path, heading) in rows {
+        match heading {
+            Some(heading) => println!("{number:>2}  {path:<width$}  {heading}"),
+            None => println!("{number:>2}  {path}"),
+        }
+    }
+
+    Ok(())
+}
+
+fn run(paths: &[String]) -> Result<(), String> {
+    // resolve every path before running anything, so that a typo in the last
+    // path doesn't only show up after the other examples have run
+    let examples = paths
+        .iter()
+        .map(|path| rbe::example(path).ok_or_else(|| format!("no example named '{path}'")))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (_, example) in examples {
+        (example.run)();
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = parse_args(&args).and_then(|command| match command {
+        Command::List(needle) => list(needle.as_deref()),
+        Command::Run(paths) => run(&paths),
+        Command::RunChapter(needle) => find_chapter(&needle).map(Chapter::run_all),
+    });
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,248 @@
+// Compile-fail tests for the lines the chapters leave commented out because
+// they don't compile.
+//
+// Each rejected line has a standalone fixture in
+// `<chapter>/tests/compile-fail/`, starting with the error codes rustc is
+// expected to report, and the example it was copied from:
+//
+//     // error: E0382
+//     //
+//     // From `partial_moves`: ...
+//
+// Every fixture is compiled with the local `rustc` (or `$RUSTC`). The test fails
+// if a fixture compiles, if rustc reports a code that isn't in the header, or
+// if one in the header is missing.
+//
+// Fixtures are copies, not extracts, so they have to be kept in sync with the
+// examples by hand. The test only checks that the example a fixture names is
+// still a function, or a file, in the chapter's `src/`.
+#[cfg(test)]
+mod compile_fail_tests {
+    use std::env;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+    use std::thread;
+
+    const HEADER: &str = "// error:";
+
+    fn workspace_root() -> &'static Path {
+        Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+    }
+
+    // every `.rs` file in a `tests/compile-fail` directory under `dir`
+    fn fixtures(dir: &Path) -> Vec<PathBuf> {
+        let mut found = vec![];
+        let mut entries: Vec<_> = fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .collect();
+
+        entries.sort();
+
+        for path in entries {
+            match path.file_name().and_then(|name| name.to_str()) {
+                Some("target" | ".git") => {}
+                Some("compile-fail") if path.parent().is_some_and(|p| p.ends_with("tests")) => {
+                    let mut files: Vec<_> = fs::read_dir(&path)
+                        .unwrap()
+                        .flatten()
+                        .map(|entry| entry.path())
+                        .filter(|file| file.extension().is_some_and(|ext| ext == "rs"))
+                        .collect();
+
+                    files.sort();
+                    found.extend(files);
+                }
+                _ => found.extend(fixtures(&path)),
+            }
+        }
+
+        found
+    }
+
+    // `// error: E0382, E0505` => ["E0382", "E0505"]
+    fn expected_codes(source: &str) -> Option<Vec<&str>> {
+        let codes: Vec<_> = source
+            .lines()
+            .next()?
+            .strip_prefix(HEADER)?
+            .split(',')
+            .map(str::trim)
+            .collect();
+
+        let valid = codes.iter().all(|code| {
+            code.len() == 5
+                && code.starts_with('E')
+                && code[1..].chars().all(|c| c.is_ascii_digit())
+        });
+
+        valid.then_some(codes)
+    }
+
+    // "// From `partial_moves`: ..." => "partial_moves"
+    fn source_example(source: &str) -> Option<&str> {
+        source
+            .lines()
+            .take_while(|line| line.starts_with("//"))
+            .find_map(|line| line.strip_prefix("// From `"))
+            .and_then(|rest| rest.split_once('`'))
+            .map(|(name, _)| name)
+    }
+
+    // true if a file under `dir` is called `example`, or defines a function
+    // called `example`
+    fn defines_example(dir: &Path, example: &str) -> bool {
+        let function = format!("fn {example}(");
+
+        fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .any(|entry| {
+                let path = entry.path();
+
+                if path.is_dir() {
+                    defines_example(&path, example)
+                } else {
+                    path.file_name().is_some_and(|name| name == example)
+                        || fs::read_to_string(&path).is_ok_and(|code| code.contains(&function))
+                }
+            })
+    }
+
+    // every `error[EXXXX]` rustc reported
+    fn reported_codes(stderr: &str) -> Vec<&str> {
+        stderr
+            .match_indices("error[E")
+            .filter_map(|(i, _)| stderr.get(i + 6..i + 11))
+            .collect()
+    }
+
+    fn check_fixture(path: &Path, out_dir: &Path) -> Result<(), String> {
+        let name = path
+            .strip_prefix(workspace_root())
+            .unwrap_or(path)
+            .display();
+        let source = fs::read_to_string(path).map_err(|e| format!("{name}: {e}"))?;
+        let codes = expected_codes(&source)
+            .ok_or_else(|| format!("{name}: missing a `{HEADER} EXXXX` header"))?;
+        let example = source_example(&source)
+            .ok_or_else(|| format!("{name}: missing a \"// From `example`\" line"))?;
+        // <chapter>/tests/compile-fail/<fixture>.rs
+        let chapter = path.ancestors().nth(3).unwrap_or(path);
+
+        if !defines_example(&chapter.join("src"), example) {
+            return Err(format!(
+                "{name}: copied from `{example}`, which is no longer in {}",
+                chapter.join("src").display()
+            ));
+        }
+
+        let output = Command::new(env::var_os("RUSTC").unwrap_or("rustc".into()))
+            .args([
+                "--edition",
+                "2021",
+                "--crate-type",
+                "bin",
+                "--emit=metadata",
+            ])
+            .arg("--out-dir")
+            .arg(out_dir)
+            .arg(path)
+            .output()
+            .map_err(|e| format!("{name}: failed to run rustc: {e}"))?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if output.status.success() {
+            return Err(format!(
+                "{name}: snippet now compiles, expected {}",
+                codes.join(", ")
+            ));
+        }
+
+        let reported = reported_codes(&stderr);
+        let missing: Vec<_> = codes
+            .iter()
+            .filter(|code| !reported.contains(code))
+            .collect();
+        let unexpected: Vec<_> = reported
+            .iter()
+            .filter(|code| !codes.contains(code))
+            .collect();
+
+        if missing.is_empty() && unexpected.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "{name}: expected {codes:?}, but rustc reported:\n{stderr}"
+            ))
+        }
+    }
+
+    #[test]
+    fn rejected_snippets_still_fail_to_compile() {
+        let fixtures = fixtures(workspace_root());
+
+        assert!(!fixtures.is_empty(), "no compile-fail fixtures found");
+
+        let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("compile-fail");
+
+        // each fixture gets its own output directory, so rustc processes
+        // don't write over each other
+        let failures: Vec<String> = thread::scope(|scope| {
+            let handles: Vec<_> = fixtures
+                .iter()
+                .enumerate()
+                .map(|(i, path)| {
+                    let out_dir = out_dir.join(i.to_string());
+
+                    scope.spawn(move || check_fixture(path, &out_dir).err())
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .filter_map(|handle| handle.join().unwrap())
+                .collect()
+        });
+
+        assert!(
+            failures.is_empty(),
+            "{} compile-fail failure(s):\n\n{}",
+            failures.len(),
+            failures.join("\n\n")
+        );
+    }
+
+    #[test]
+    fn headers_list_error_codes() {
+        assert_eq!(expected_codes("// error: E0382\n"), Some(vec!["E0382"]));
+        assert_eq!(
+            expected_codes("// error: E0502,E0505\nfn main() {}"),
+            Some(vec!["E0502", "E0505"])
+        );
+        assert_eq!(expected_codes("// error: borrow\n"), None);
+        assert_eq!(expected_codes("fn main() {}\n// error: E0382"), None);
+    }
+
+    #[test]
+    fn fixtures_name_their_example() {
+        let source = "// error: E0382\n//\n// From `partial_moves`: moved\nfn main() {}";
+
+        assert_eq!(source_example(source), Some("partial_moves"));
+        assert_eq!(source_example("// error: E0382\nfn main() {}"), None);
+        // only the header comment counts
+        assert_eq!(
+            source_example("// error: E0382\nfn main() {}\n// From `x`:"),
+            None
+        );
+        assert_eq!(
+            reported_codes("error[E0382]: moved\nerror: aborting\nerror[E0505]: x"),
+            ["E0382", "E0505"]
+        );
+    }
+}
@@ -0,0 +1,281 @@
+// Golden-output tests for every registered example.
+//
+// Each deterministic example is run through the `rbe` binary, and its stdout
+// and stderr are compared against the files in `tests/snapshots/<chapter>/`.
+// After an intentional change to what an example prints, update the snapshots
+// with:
+//
+//     RBE_BLESS=1 cargo test -p rbe --test snapshots
+#[cfg(test)]
+mod snapshot_tests {
+    use rbe_registry::{Chapter, Example, Output};
+    use std::collections::HashSet;
+    use std::env;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::process::{Command, Stdio};
+    use std::thread;
+
+    // how many hex digits `{:p}` prints for an address
+    const POINTER_DIGITS: std::ops::RangeInclusive<usize> = 8..=16;
+
+    struct Captured {
+        stdout: String,
+        stderr: String,
+        success: bool,
+    }
+
+    fn snapshot_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots")
+    }
+
+    fn is_blessing() -> bool {
+        env::var_os("RBE_BLESS").is_some_and(|value| value != "0")
+    }
+
+    fn run_example(chapter: &Chapter, example: &Example) -> Captured {
+        let output = Command::new(env!("CARGO_BIN_EXE_rbe"))
+            .args(["run", &format!("{}::{}", chapter.name, example.name)])
+            .env("RUST_BACKTRACE", "0")
+            // keep terminal colors out of the snapshots
+            .env("NO_COLOR", "1")
+            .stdin(Stdio::null())
+            .output()
+            .expect("failed to run rbe");
+
+        Captured {
+            stdout: redact(&String::from_utf8_lossy(&output.stdout)),
+            stderr: redact(&String::from_utf8_lossy(&output.stderr)),
+            success: output.status.success(),
+        }
+    }
+
+    // remove the parts of the output that change between runs, or whenever
+    // unrelated code is edited
+    fn redact(output: &str) -> String {
+        output
+            .lines()
+            .map(|line| redact_line_numbers(&redact_addresses(&redact_thread_ids(line))))
+            .map(|line| line + "\n")
+            .collect()
+    }
+
+    // thread 'main' (1234) panicked at ... => thread 'main' panicked at ...
+    fn redact_thread_ids(line: &str) -> String {
+        match (line.find("' ("), line.find(") panicked at")) {
+            (Some(start), Some(end)) if start < end => {
+                format!("{}'{}", &line[..start], &line[end + 1..])
+            }
+            _ => line.to_string(),
+        }
+    }
+
+    // 0x7ffd5e8c1a2c => 0x[ADDR]. Only runs as long as a pointer on a 32 or
+    // 64-bit target are redacted, so shorter hex numbers such as `0x80FF5A`,
+    // and longer ones such as big integers, are still compared
+    fn redact_addresses(line: &str) -> String {
+        let mut result = String::new();
+        let mut rest = line;
+
+        while let Some(index) = rest.find("0x") {
+            let (before, after) = rest.split_at(index);
+            let digits = after[2..]
+                .find(|c: char| !c.is_ascii_hexdigit())
+                .unwrap_or(after.len() - 2);
+
+            result.push_str(before);
+
+            if POINTER_DIGITS.contains(&digits) {
+                result.push_str("0x[ADDR]");
+            } else {
+                result.push_str(&after[..2 + digits]);
+            }
+
+            rest = &after[2 + digits..];
+        }
+
+        result + rest
+    }
+
+    // src/lib.rs:42:13 => src/lib.rs:LL:CC
+    fn redact_line_numbers(line: &str) -> String {
+        let mut result = String::new();
+        let mut rest = line;
+
+        while let Some(index) = rest.find(".rs:") {
+            let (before, after) = rest.split_at(index + ".rs:".len());
+            let location = after
+                .find(|c: char| !c.is_ascii_digit() && c != ':')
+                .map_or(after, |end| &after[..end])
+                .trim_end_matches(':');
+
+            result.push_str(before);
+
+            match location.split_once(':') {
+                Some((line, column)) if !line.is_empty() && !column.is_empty() => {
+                    result.push_str("LL:CC")
+                }
+                _ => result.push_str(location),
+            }
+
+            rest = &after[location.len()..];
+        }
+
+        result + rest
+    }
+
+    // compare `actual` against the snapshot at `path`, or overwrite the
+    // snapshot when blessing. Empty output is stored as a missing file.
+    fn check_snapshot(path: &Path, actual: &str) -> Result<(), String> {
+        if is_blessing() {
+            if actual.is_empty() {
+                if path.exists() {
+                    fs::remove_file(path).map_err(|e| e.to_string())?;
+                }
+            } else {
+                fs::create_dir_all(path.parent().unwrap()).map_err(|e| e.to_string())?;
+                fs::write(path, actual).map_err(|e| e.to_string())?;
+            }
+
+            return Ok(());
+        }
+
+        let expected = fs::read_to_string(path).unwrap_or_default();
+
+        if expected == actual {
+            return Ok(());
+        }
+
+        let mismatch = expected
+            .lines()
+            .chain(std::iter::repeat("<end of output>"))
+            .zip(actual.lines().chain(std::iter::repeat("<end of output>")))
+            .enumerate()
+            .find(|(_, (e, a))| e != a);
+        let detail = match mismatch {
+            Some((i, (e, a))) => format!("line {}:\n  expected: {e}\n    actual: {a}", i + 1),
+            None => "output differs in trailing whitespace".to_string(),
+        };
+
+        Err(format!("{} does not match\n{detail}", path.display()))
+    }
+
+    fn check_example(chapter: &Chapter, example: &Example) -> Vec<String> {
+        let path = format!("{}::{}", chapter.name, example.name);
+
+        match example.output {
+            Output::Interactive => vec![],
+            Output::Nondeterministic => {
+                let captured = run_example(chapter, example);
+
+                if captured.success {
+                    vec![]
+                } else {
+                    vec![format!("{path} failed:\n{}", captured.stderr)]
+                }
+            }
+            Output::Deterministic => {
+                let captured = run_example(chapter, example);
+                let dir = snapshot_dir().join(chapter.name);
+
+                [("stdout", &captured.stdout), ("stderr", &captured.stderr)]
+                    .into_iter()
+                    .filter_map(|(stream, actual)| {
+                        let file = dir.join(format!("{}.{stream}", example.name));
+
+                        check_snapshot(&file, actual)
+                            .err()
+                            .map(|e| format!("{path}: {e}"))
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    // snapshot files that don't belong to any deterministic example
+    fn stale_snapshots() -> Vec<PathBuf> {
+        let expected: HashSet<PathBuf> = rbe::CHAPTERS
+            .iter()
+            .flat_map(|chapter| {
+                chapter
+                    .examples
+                    .iter()
+                    .filter(|example| example.output == Output::Deterministic)
+                    .flat_map(move |example| {
+                        ["stdout", "stderr"].map(|stream| {
+                            snapshot_dir()
+                                .join(chapter.name)
+                                .join(format!("{}.{stream}", example.name))
+                        })
+                    })
+            })
+            .collect();
+
+        fs::read_dir(snapshot_dir())
+            .into_iter()
+            .flatten()
+            .flatten()
+            .flat_map(|dir| fs::read_dir(dir.path()).into_iter().flatten().flatten())
+            .map(|entry| entry.path())
+            .filter(|path| !expected.contains(path))
+            .collect()
+    }
+
+    #[test]
+    fn examples_match_snapshots() {
+        // run each chapter on its own thread - arc_example alone sleeps
+        // for a second
+        let mut failures: Vec<String> = thread::scope(|scope| {
+            let handles: Vec<_> = rbe::CHAPTERS
+                .iter()
+                .map(|chapter| {
+                    scope.spawn(move || {
+                        chapter
+                            .examples
+                            .iter()
+                            .flat_map(|example| check_example(chapter, example))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap())
+                .collect()
+        });
+
+        for path in stale_snapshots() {
+            if is_blessing() {
+                fs::remove_file(&path).unwrap();
+            } else {
+                failures.push(format!("stale snapshot: {}", path.display()));
+            }
+        }
+
+        assert!(
+            failures.is_empty(),
+            "{} snapshot failure(s):\n\n{}\n\nrun `RBE_BLESS=1 cargo test -p rbe --test snapshots` to update the snapshots",
+            failures.len(),
+            failures.join("\n\n")
+        );
+    }
+
+    #[test]
+    fn redact_removes_unstable_output() {
+        let output = "\
+thread 'main' (5972) panicked at 09-functions/src/lib.rs:512:9:
+xs[0] address: 0x55d0c3a1bb10
+Ok(0x)
+";
+
+        assert_eq!(
+            redact(output),
+            "\
+thread 'main' panicked at 09-functions/src/lib.rs:LL:CC:
+xs[0] address: 0x[ADDR]
+Ok(0x)
+"
+        );
+    }
+}
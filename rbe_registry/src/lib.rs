@@ -0,0 +1,118 @@
+// A registry of the example functions in each chapter.
+//
+// Each chapter crate exposes a `CHAPTER` constant built with `chapter!`, which
+// lists its examples in the order they were previously called from `main`,
+// grouped under the heading comments that `main` used to have, e.g.
+// `// drop` or `// iterators`.
+
+// whether an example prints the same output every time it is run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Output {
+    Deterministic,
+    // e.g. random values, HashMap iteration order, or thread scheduling
+    Nondeterministic,
+    // reads from stdin
+    Interactive,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+    pub name: &'static str,
+    // the heading the example falls under, if any
+    pub heading: Option<&'static str>,
+    pub output: Output,
+    pub run: fn(),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Chapter {
+    pub number: u8,
+    pub name: &'static str,
+    pub examples: &'static [Example],
+}
+
+impl Chapter {
+    // run every example in the chapter, in order
+    pub fn run_all(&self) {
+        for example in self.examples {
+            (example.run)();
+        }
+    }
+
+    pub fn example(&self, name: &str) -> Option<&'static Example> {
+        self.examples.iter().find(|example| example.name == name)
+    }
+
+    // a chapter can be referred to either by its number, or by its name
+    pub fn matches(&self, needle: &str) -> bool {
+        match needle.parse::<u8>() {
+            Ok(number) => number == self.number,
+            Err(_) => needle == self.name,
+        }
+    }
+}
+
+// Build a `Chapter` from groups of example functions:
+//
+// ```
+// pub const CHAPTER: Chapter = chapter! {
+//     number: 16,
+//     name: "traits",
+//     examples: {
+//         _: [internal_access, instantiate_via_trait],
+//         "deriving": [default_trait, nan_never_equal],
+//         "dyn": [dynamic_trait_no_struct, #[nondeterministic] return_trait_with_dyn],
+//     },
+// };
+// ```
+//
+// `_` marks a group of examples without a heading. Examples may return a
+// value - it is discarded when the example is run.
+//
+// Examples are assumed to be deterministic - mark them with
+// `#[nondeterministic]` or `#[interactive]` otherwise.
+#[macro_export]
+macro_rules! chapter {
+    (@heading _) => {
+        None
+    };
+
+    (@heading $heading: literal) => {
+        Some($heading)
+    };
+
+    (@output) => {
+        $crate::Output::Deterministic
+    };
+
+    (@output nondeterministic) => {
+        $crate::Output::Nondeterministic
+    };
+
+    (@output interactive) => {
+        $crate::Output::Interactive
+    };
+
+    (
+        number: $number: literal,
+        name: $name: literal,
+        examples: {
+            $($heading: tt: [$($(#[$output: ident])? $example: ident),+ $(,)?]),+ $(,)?
+        } $(,)?
+    ) => {
+        $crate::Chapter {
+            number: $number,
+            name: $name,
+            examples: &[$($(
+                $crate::Example {
+                    name: stringify!($example),
+                    heading: $crate::chapter!(@heading $heading),
+                    output: $crate::chapter!(@output $($output)?),
+                    run: || {
+                        $example();
+                    },
+                }
+            ),+),+],
+        }
+    };
+}